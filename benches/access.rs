@@ -0,0 +1,30 @@
+//! Measures accessors over mixed inline and heap strings, which have to select variant on every access.
+
+use std::hint::black_box;
+
+mod util;
+use util::bench;
+
+fn main() {
+    //Interleaved in pseudo random order so that variant cannot be predicted.
+    let mut state = 0x2545_f491_u32;
+    let strings: Vec<stroka::String> = (0..1024).map(|_| {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        match state % 2 {
+            0 => stroka::String::new_str("lolka"),
+            _ => stroka::String::new_str("lolka lol lolid by loli"),
+        }
+    }).collect();
+    let std_strings: Vec<String> = strings.iter().map(|string| string.as_str().to_owned()).collect();
+
+    bench("mixed/stroka len+as_bytes", || {
+        let strings = black_box(&strings);
+        strings.iter().fold(0usize, |acc, string| acc.wrapping_add(string.len()).wrapping_add(string.as_bytes()[0] as usize))
+    });
+    bench("mixed/std len+as_bytes", || {
+        let strings = black_box(&std_strings);
+        strings.iter().fold(0usize, |acc, string| acc.wrapping_add(string.len()).wrapping_add(string.as_bytes()[0] as usize))
+    });
+}
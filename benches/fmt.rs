@@ -0,0 +1,43 @@
+//! Compares single pass formatting against formatting sized upfront.
+
+use core::fmt::Write;
+use std::hint::black_box;
+
+mod util;
+use util::bench;
+
+fn main() {
+    let value = black_box(123456789u64);
+    let text = black_box("lolka lol lolid by loli");
+
+    bench("literal/format", || stroka::format!("lolka lol lolid by loli"));
+    bench("literal/write_fmt", || {
+        let mut res = stroka::String::new();
+        let _ = res.write_fmt(format_args!("lolka lol lolid by loli"));
+        res
+    });
+
+    bench("single arg/format", || stroka::format!("{}", value));
+    bench("single arg/write_fmt", || {
+        let mut res = stroka::String::new();
+        let _ = res.write_fmt(format_args!("{}", value));
+        res
+    });
+    bench("single arg/from_fmt", || stroka::String::from_fmt(format_args!("{}", value)));
+
+    bench("many small fragments/write_fmt", || {
+        let mut res = stroka::String::new();
+        let _ = res.write_fmt(format_args!("{}:{}:{}:{}:{}:{}:{}:{}:{}:{}:{}:{}", value, value, value, value, value, value, value, value, value, value, value, value));
+        res
+    });
+    bench("many small fragments/from_fmt", || {
+        stroka::String::from_fmt(format_args!("{}:{}:{}:{}:{}:{}:{}:{}:{}:{}:{}:{}", value, value, value, value, value, value, value, value, value, value, value, value))
+    });
+
+    bench("few large fragments/write_fmt", || {
+        let mut res = stroka::String::new();
+        let _ = res.write_fmt(format_args!("{} {} {}", text, text, text));
+        res
+    });
+    bench("few large fragments/from_fmt", || stroka::String::from_fmt(format_args!("{} {} {}", text, text, text)));
+}
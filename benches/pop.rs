@@ -0,0 +1,34 @@
+//! Measures popping every character, which takes linear time only if `pop` does not walk whole string.
+
+use std::hint::black_box;
+
+mod util;
+use util::bench;
+
+//Former implementation of `pop`.
+fn pop_from_front(string: &mut stroka::String) -> Option<char> {
+    let ch = string.as_str().chars().last()?;
+    string.truncate(string.len() - ch.len_utf8());
+    Some(ch)
+}
+
+fn main() {
+    for len in [1_000usize, 10_000, 100_000].iter() {
+        let text = stroka::String::new_str(&"lolka単語".repeat(len / 11));
+
+        bench(&format!("pop all {}/pop", text.len()), || {
+            let mut string = text.clone();
+            while let Some(ch) = string.pop() {
+                black_box(ch);
+            }
+            string
+        });
+        bench(&format!("pop all {}/chars().last()", text.len()), || {
+            let mut string = text.clone();
+            while let Some(ch) = pop_from_front(&mut string) {
+                black_box(ch);
+            }
+            string
+        });
+    }
+}
@@ -0,0 +1,32 @@
+//! Measures tight `push` loop that stays within inline capacity.
+
+use std::hint::black_box;
+
+mod util;
+use util::bench;
+
+fn main() {
+    let ch = black_box('l');
+
+    bench("push within sso/stroka", || {
+        let mut string = stroka::String::new();
+        for _ in 0..stroka::String::INLINE_CAPACITY {
+            string.push(ch);
+        }
+        string
+    });
+    bench("push_str within sso/stroka", || {
+        let mut string = stroka::String::new();
+        for _ in 0..stroka::String::INLINE_CAPACITY / 2 {
+            string.push_str(black_box("lo"));
+        }
+        string
+    });
+    bench("push within capacity/std", || {
+        let mut string = String::with_capacity(stroka::String::INLINE_CAPACITY);
+        for _ in 0..stroka::String::INLINE_CAPACITY {
+            string.push(ch);
+        }
+        string
+    });
+}
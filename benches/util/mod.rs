@@ -0,0 +1,40 @@
+//! Minimal benchmark harness, to avoid pulling in benchmark framework as dependency.
+//!
+//! Run with `cargo bench --bench <name> [filter]`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const TARGET_TIME: Duration = Duration::from_millis(500);
+
+//Returns whether benchmark `name` is selected by command line filter.
+fn is_selected(name: &str) -> bool {
+    let mut filters = std::env::args().skip(1).filter(|arg| !arg.starts_with('-')).peekable();
+    filters.peek().is_none() || filters.any(|filter| name.contains(filter.as_str()))
+}
+
+///Runs `fun` until it takes enough time to measure, and prints average time per iteration.
+pub fn bench<R, F: FnMut() -> R>(name: &str, mut fun: F) {
+    if !is_selected(name) {
+        return;
+    }
+
+    for _ in 0..1_000 {
+        black_box(fun());
+    }
+
+    let mut iterations = 1_000u64;
+    loop {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            black_box(fun());
+        }
+        let elapsed = start.elapsed();
+
+        if elapsed >= TARGET_TIME || iterations >= 1 << 32 {
+            println!("{:<48} {:>12.2} ns/iter", name, elapsed.as_nanos() as f64 / iterations as f64);
+            break;
+        }
+        iterations *= 2;
+    }
+}
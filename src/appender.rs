@@ -0,0 +1,189 @@
+use crate::{SmallString, IncompleteUtf8Error, SSO_MAX_SIZE};
+use crate::lossy::utf8_chunks;
+
+///Appender of UTF-8 input, arriving in chunks, into `String` or any other `SmallString`.
+///
+///Characters split across chunks are carried over until the next chunk, instead of being
+///reported as invalid.
+///
+///Input must be terminated by calling [finish](#method.finish), otherwise incomplete character
+///at the end is silently discarded.
+pub struct Utf8Appender<'a, const N: usize = SSO_MAX_SIZE> {
+    string: &'a mut SmallString<N>,
+    pending: [u8; 4],
+    pending_len: usize,
+    lossy: bool,
+}
+
+impl<'a, const N: usize> Utf8Appender<'a, N> {
+    #[inline]
+    ///Creates new appender, rejecting invalid UTF-8.
+    pub fn new(string: &'a mut SmallString<N>) -> Self {
+        Self {
+            string,
+            pending: [0; 4],
+            pending_len: 0,
+            lossy: false,
+        }
+    }
+
+    #[inline]
+    ///Creates new appender, replacing invalid UTF-8 with [REPLACEMENT_CHARACTER](https://doc.rust-lang.org/core/char/constant.REPLACEMENT_CHARACTER.html)
+    pub fn lossy(string: &'a mut SmallString<N>) -> Self {
+        Self {
+            lossy: true,
+            ..Self::new(string)
+        }
+    }
+
+    #[inline(always)]
+    ///Returns bytes of incomplete character, carried over from previous chunk.
+    pub fn pending(&self) -> &[u8] {
+        &self.pending[..self.pending_len]
+    }
+
+    #[inline(always)]
+    ///Returns whether invalid UTF-8 is replaced instead of being rejected.
+    pub const fn is_lossy(&self) -> bool {
+        self.lossy
+    }
+
+    ///Appends chunk of input.
+    ///
+    ///In case of invalid UTF-8, returns error, appending only valid input preceding it.
+    ///Error is never returned in lossy mode.
+    ///
+    ///Note that error describes input processed by the call, hence its offset is only meaningful
+    ///when there is no [pending](#method.pending) character.
+    pub fn push_chunk(&mut self, bytes: &[u8]) -> Result<(), core::str::Utf8Error> {
+        let consumed = self.complete_pending(bytes)?;
+        let bytes = &bytes[consumed..];
+
+        if self.lossy {
+            let mut chunks = utf8_chunks(bytes).peekable();
+            while let Some(chunk) = chunks.next() {
+                self.string.push_str(chunk.valid());
+                let invalid = chunk.invalid();
+                if invalid.is_empty() {
+                    continue;
+                }
+
+                match chunks.peek().is_none() && is_incomplete(invalid) {
+                    true => self.set_pending(invalid),
+                    false => self.string.push(core::char::REPLACEMENT_CHARACTER),
+                }
+            }
+            Ok(())
+        } else {
+            match core::str::from_utf8(bytes) {
+                Ok(text) => {
+                    self.string.push_str(text);
+                    Ok(())
+                },
+                Err(error) => {
+                    let (valid, invalid) = bytes.split_at(error.valid_up_to());
+                    self.string.push_str(unsafe {
+                        core::str::from_utf8_unchecked(valid)
+                    });
+
+                    match error.error_len() {
+                        None => {
+                            self.set_pending(invalid);
+                            Ok(())
+                        },
+                        Some(_) => Err(error),
+                    }
+                }
+            }
+        }
+    }
+
+    ///Finishes input.
+    ///
+    ///In case of incomplete character at the end of input, returns error with its bytes.
+    ///In lossy mode such character is replaced with [REPLACEMENT_CHARACTER](https://doc.rust-lang.org/core/char/constant.REPLACEMENT_CHARACTER.html) instead.
+    pub fn finish(self) -> Result<(), IncompleteUtf8Error> {
+        match self.pending_len {
+            0 => Ok(()),
+            _ if self.lossy => {
+                self.string.push(core::char::REPLACEMENT_CHARACTER);
+                Ok(())
+            },
+            _ => Err(self.pending_error()),
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn pending_error(&self) -> IncompleteUtf8Error {
+        IncompleteUtf8Error {
+            bytes: self.pending,
+            len: self.pending_len as u8,
+        }
+    }
+
+    #[inline(always)]
+    fn set_pending(&mut self, bytes: &[u8]) {
+        debug_assert!(bytes.len() < self.pending.len());
+        self.pending[..bytes.len()].copy_from_slice(bytes);
+        self.pending_len = bytes.len();
+    }
+
+    //Completes pending character using start of `bytes`, returning number of consumed bytes.
+    fn complete_pending(&mut self, bytes: &[u8]) -> Result<usize, core::str::Utf8Error> {
+        let pending_len = self.pending_len;
+        if pending_len == 0 {
+            return Ok(0);
+        }
+
+        let mut buf = [0u8; 4];
+        let head_len = core::cmp::min(bytes.len(), buf.len() - pending_len);
+        buf[..pending_len].copy_from_slice(self.pending());
+        buf[pending_len..pending_len + head_len].copy_from_slice(&bytes[..head_len]);
+        let buf = &buf[..pending_len + head_len];
+
+        let (valid_len, error) = match core::str::from_utf8(buf) {
+            Ok(_) => (buf.len(), None),
+            Err(error) => (error.valid_up_to(), Some(error)),
+        };
+
+        //Pending bytes are start of a character, hence valid input means it is complete now
+        let error = match error {
+            Some(error) if valid_len == 0 => error,
+            _ => {
+                let text = unsafe {
+                    core::str::from_utf8_unchecked(&buf[..valid_len])
+                };
+                let ch_len = text.chars().next().map_or(0, char::len_utf8);
+                self.string.push_str(&text[..ch_len]);
+                self.pending_len = 0;
+                return Ok(ch_len - pending_len);
+            }
+        };
+
+        match error.error_len() {
+            //still incomplete, hence all of input is consumed
+            None => {
+                self.set_pending(buf);
+                Ok(head_len)
+            },
+            Some(_) if !self.lossy => {
+                self.pending_len = 0;
+                Err(error)
+            },
+            //Pending bytes are valid prefix, hence invalid sequence includes them all
+            Some(invalid_len) => {
+                self.string.push(core::char::REPLACEMENT_CHARACTER);
+                self.pending_len = 0;
+                Ok(invalid_len - pending_len)
+            }
+        }
+    }
+}
+
+#[inline(always)]
+fn is_incomplete(bytes: &[u8]) -> bool {
+    match core::str::from_utf8(bytes) {
+        Err(error) => error.error_len().is_none(),
+        Ok(_) => false,
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{SmallString, SSO_MAX_SIZE};
+
+use core::ops::{Deref, DerefMut};
+
+///Guard over mutable bytes of ASCII only `String`.
+///
+///On drop, every byte is masked to ASCII range, ensuring string remains valid UTF-8.
+///
+///While guard is alive, string is empty, and its content is restored only on drop, after masking.
+///Hence leaking guard (e.g. via `mem::forget`) leaves string empty, rather than with invalid UTF-8.
+pub struct AsciiBytesMut<'a, const N: usize = SSO_MAX_SIZE> {
+    string: &'a mut SmallString<N>,
+    len: usize,
+}
+
+impl<'a, const N: usize> AsciiBytesMut<'a, N> {
+    #[inline]
+    pub(crate) fn new(string: &'a mut SmallString<N>) -> Self {
+        let len = string.len();
+        unsafe {
+            string.set_len(0);
+        }
+
+        Self {
+            string,
+            len,
+        }
+    }
+}
+
+impl<const N: usize> Deref for AsciiBytesMut<'_, N> {
+    type Target = [u8];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            core::slice::from_raw_parts(self.string.as_ptr(), self.len)
+        }
+    }
+}
+
+impl<const N: usize> DerefMut for AsciiBytesMut<'_, N> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe {
+            core::slice::from_raw_parts_mut(self.string.as_mut_ptr(), self.len)
+        }
+    }
+}
+
+impl<const N: usize> Drop for AsciiBytesMut<'_, N> {
+    #[inline]
+    fn drop(&mut self) {
+        for byte in self.deref_mut().iter_mut() {
+            *byte &= 0x7F;
+        }
+
+        unsafe {
+            self.string.set_len(self.len);
+        }
+    }
+}
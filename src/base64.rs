@@ -0,0 +1,208 @@
+use crate::SmallString;
+
+use core::fmt;
+use alloc::vec::Vec;
+
+const STANDARD_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const PAD: u8 = b'=';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Base64 encoding configuration.
+pub struct Base64Config {
+    ///Specifies to use URL-safe alphabet (`-` and `_` instead of `+` and `/`).
+    pub url_safe: bool,
+    ///Specifies to pad output with `=`, making its length multiple of 4.
+    pub padding: bool,
+}
+
+impl Base64Config {
+    ///Standard alphabet with padding.
+    pub const STANDARD: Self = Self {
+        url_safe: false,
+        padding: true,
+    };
+
+    ///Standard alphabet without padding.
+    pub const STANDARD_NO_PAD: Self = Self {
+        url_safe: false,
+        padding: false,
+    };
+
+    ///URL-safe alphabet with padding.
+    pub const URL_SAFE: Self = Self {
+        url_safe: true,
+        padding: true,
+    };
+
+    ///URL-safe alphabet without padding.
+    pub const URL_SAFE_NO_PAD: Self = Self {
+        url_safe: true,
+        padding: false,
+    };
+
+    #[inline(always)]
+    const fn alphabet(&self) -> &'static [u8; 64] {
+        match self.url_safe {
+            true => URL_SAFE_ALPHABET,
+            false => STANDARD_ALPHABET,
+        }
+    }
+
+    #[inline]
+    ///Returns exact length of encoded output for input of `len` bytes.
+    pub const fn encoded_len(&self, len: usize) -> usize {
+        let full = len / 3 * 4;
+        match (len % 3, self.padding) {
+            (0, _) => full,
+            (_, true) => full + 4,
+            (rem, false) => full + rem + 1,
+        }
+    }
+}
+
+impl Default for Base64Config {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Base64 decoding error.
+pub struct Base64DecodeError {
+    offset: usize,
+}
+
+impl Base64DecodeError {
+    #[inline(always)]
+    ///Returns byte offset of the first invalid character.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for Base64DecodeError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid base64 character at offset {}", self.offset)
+    }
+}
+
+pub(crate) fn encode<const N: usize>(bytes: &[u8], cfg: Base64Config) -> SmallString<N> {
+    let alphabet = cfg.alphabet();
+    let len = cfg.encoded_len(bytes.len());
+    let mut res = SmallString::with_capacity(len);
+    let out = res.as_mut_ptr();
+    let mut cursor = 0;
+
+    macro_rules! write_byte {
+        ($byte:expr) => {
+            unsafe {
+                out.add(cursor).write($byte);
+            }
+            cursor += 1;
+        }
+    }
+
+    let mut chunks = bytes.chunks_exact(3);
+    for chunk in &mut chunks {
+        let group = (chunk[0] as u32) << 16 | (chunk[1] as u32) << 8 | chunk[2] as u32;
+        write_byte!(alphabet[(group >> 18) as usize & 0x3f]);
+        write_byte!(alphabet[(group >> 12) as usize & 0x3f]);
+        write_byte!(alphabet[(group >> 6) as usize & 0x3f]);
+        write_byte!(alphabet[group as usize & 0x3f]);
+    }
+
+    match chunks.remainder() {
+        [first] => {
+            let group = (*first as u32) << 16;
+            write_byte!(alphabet[(group >> 18) as usize & 0x3f]);
+            write_byte!(alphabet[(group >> 12) as usize & 0x3f]);
+            if cfg.padding {
+                write_byte!(PAD);
+                write_byte!(PAD);
+            }
+        },
+        [first, second] => {
+            let group = (*first as u32) << 16 | (*second as u32) << 8;
+            write_byte!(alphabet[(group >> 18) as usize & 0x3f]);
+            write_byte!(alphabet[(group >> 12) as usize & 0x3f]);
+            write_byte!(alphabet[(group >> 6) as usize & 0x3f]);
+            if cfg.padding {
+                write_byte!(PAD);
+            }
+        },
+        _ => (),
+    }
+
+    debug_assert_eq!(cursor, len);
+    unsafe {
+        res.set_len(len);
+    }
+    res
+}
+
+#[inline(always)]
+const fn decode_byte(byte: u8) -> Option<u8> {
+    match byte {
+        b'A'..=b'Z' => Some(byte - b'A'),
+        b'a'..=b'z' => Some(byte - b'a' + 26),
+        b'0'..=b'9' => Some(byte - b'0' + 52),
+        b'+' | b'-' => Some(62),
+        b'/' | b'_' => Some(63),
+        _ => None,
+    }
+}
+
+pub(crate) fn decode(text: &str) -> Result<Vec<u8>, Base64DecodeError> {
+    let bytes = text.as_bytes();
+    let mut data_len = bytes.len();
+    while data_len > 0 && bytes.len() - data_len < 2 && bytes[data_len - 1] == PAD {
+        data_len -= 1;
+    }
+
+    let mut res = Vec::with_capacity(data_len / 4 * 3 + (data_len % 4).saturating_sub(1));
+    let mut group = 0u32;
+    let mut group_len = 0;
+    for (offset, byte) in bytes[..data_len].iter().enumerate() {
+        match decode_byte(*byte) {
+            Some(value) => {
+                group = group << 6 | value as u32;
+                group_len += 1;
+            },
+            None => return Err(Base64DecodeError {
+                offset,
+            }),
+        }
+
+        if group_len == 4 {
+            res.push((group >> 16) as u8);
+            res.push((group >> 8) as u8);
+            res.push(group as u8);
+            group = 0;
+            group_len = 0;
+        }
+    }
+
+    //Padding is only valid when it completes final group
+    if data_len != bytes.len() && (bytes.len() & 3) != 0 {
+        return Err(Base64DecodeError {
+            offset: data_len,
+        });
+    }
+
+    match group_len {
+        1 => return Err(Base64DecodeError {
+            offset: data_len - 1,
+        }),
+        2 => res.push((group >> 4) as u8),
+        3 => {
+            res.push((group >> 10) as u8);
+            res.push((group >> 2) as u8);
+        },
+        _ => (),
+    }
+
+    Ok(res)
+}
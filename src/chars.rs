@@ -0,0 +1,149 @@
+use crate::{SmallString, SSO_MAX_SIZE};
+use core::iter::{FusedIterator, DoubleEndedIterator};
+
+///Owned iterator over characters of `String`
+pub struct IntoChars<const N: usize = SSO_MAX_SIZE> {
+    pub(crate) string: SmallString<N>,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+impl<const N: usize> IntoChars<N> {
+    #[inline]
+    ///Returns the remaining sub-string of this iterator.
+    pub fn as_str(&self) -> &str {
+        unsafe {
+            self.string.as_str().get_unchecked(self.start..self.end)
+        }
+    }
+}
+
+impl<const N: usize> Iterator for IntoChars<N> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next()?;
+        self.start += ch.len_utf8();
+        Some(ch)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.as_str().chars().size_hint()
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<char> {
+        self.next_back()
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for IntoChars<N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next_back()?;
+        self.end -= ch.len_utf8();
+        Some(ch)
+    }
+}
+
+impl<const N: usize> FusedIterator for IntoChars<N> {}
+
+impl<const N: usize> IntoIterator for SmallString<N> {
+    type Item = char;
+    type IntoIter = IntoChars<N>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_chars()
+    }
+}
+
+///Iterator over byte offsets of character boundaries, including end of string.
+///
+///Created by [char_boundaries](trait.StrExt.html#tymethod.char_boundaries).
+#[derive(Clone)]
+pub struct CharBoundaries<'a> {
+    pub(crate) bytes: &'a [u8],
+    pub(crate) front: usize,
+    pub(crate) back: usize,
+    pub(crate) finished: bool,
+}
+
+impl<'a> CharBoundaries<'a> {
+    #[inline(always)]
+    pub(crate) fn new(text: &'a str) -> Self {
+        Self {
+            bytes: text.as_bytes(),
+            front: 0,
+            back: text.len(),
+            finished: false,
+        }
+    }
+}
+
+#[inline(always)]
+const fn is_continuation(byte: u8) -> bool {
+    (byte & 0xC0) == 0x80
+}
+
+impl Iterator for CharBoundaries<'_> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        if self.finished {
+            return None;
+        }
+
+        let idx = self.front;
+        if idx == self.back {
+            self.finished = true;
+        } else {
+            self.front += 1;
+            while self.front < self.back && is_continuation(self.bytes[self.front]) {
+                self.front += 1;
+            }
+        }
+        Some(idx)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.finished {
+            true => (0, Some(0)),
+            false => {
+                let len = self.back - self.front;
+                (len / 4 + 1, Some(len + 1))
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<usize> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for CharBoundaries<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<usize> {
+        if self.finished {
+            return None;
+        }
+
+        let idx = self.back;
+        if idx == self.front {
+            self.finished = true;
+        } else {
+            self.back -= 1;
+            while self.back > self.front && is_continuation(self.bytes[self.back]) {
+                self.back -= 1;
+            }
+        }
+        Some(idx)
+    }
+}
+
+impl FusedIterator for CharBoundaries<'_> {}
@@ -1,43 +1,164 @@
-use crate::String;
+use crate::SmallString;
 use core::{fmt, hash};
 
-impl From<char> for String {
+impl<const N: usize> From<char> for SmallString<N> {
     #[inline(always)]
-    fn from(ch: char) -> String {
+    fn from(ch: char) -> Self {
         let mut buf = [0u8; 4];
-        Self::new_sso(ch.encode_utf8(&mut buf))
+        Self::new_str(ch.encode_utf8(&mut buf))
     }
 }
 
-impl From<&str> for String {
+impl<const N: usize> From<&str> for SmallString<N> {
     #[inline(always)]
-    fn from(s: &str) -> String {
+    fn from(s: &str) -> Self {
         Self::new_str(s)
     }
 }
 
-impl From<&mut str> for String {
+impl<const N: usize> From<&mut str> for SmallString<N> {
     #[inline(always)]
-    fn from(s: &mut str) -> String {
+    fn from(s: &mut str) -> Self {
         Self::new_str(s)
     }
 }
 
-impl From<&String> for String {
+impl<const N: usize> From<&SmallString<N>> for SmallString<N> {
     #[inline(always)]
-    fn from(s: &String) -> String {
+    fn from(s: &SmallString<N>) -> Self {
         s.clone()
     }
 }
 
-impl From<alloc::boxed::Box<str>> for String {
+impl<const N: usize> From<alloc::boxed::Box<str>> for SmallString<N> {
     #[inline(always)]
-    fn from(s: alloc::boxed::Box<str>) -> String {
+    fn from(s: alloc::boxed::Box<str>) -> Self {
         Self::new_str(&s)
     }
 }
 
-impl<'a> Extend<&'a char> for String {
+macro_rules! impl_from_display {
+    ($($typ:ty),+) => {
+        $(
+            impl<const N: usize> From<$typ> for SmallString<N> {
+                #[inline]
+                fn from(value: $typ) -> Self {
+                    let mut res = Self::new();
+                    let _ = fmt::Write::write_fmt(&mut res, format_args!("{}", value));
+                    res
+                }
+            }
+        )+
+    }
+}
+
+impl_from_display!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool, f32, f64);
+
+impl<const N: usize> From<alloc::rc::Rc<str>> for SmallString<N> {
+    #[inline(always)]
+    fn from(s: alloc::rc::Rc<str>) -> Self {
+        Self::new_str(&s)
+    }
+}
+
+impl<const N: usize> From<&alloc::rc::Rc<str>> for SmallString<N> {
+    #[inline(always)]
+    fn from(s: &alloc::rc::Rc<str>) -> Self {
+        Self::new_str(s)
+    }
+}
+
+impl<const N: usize> From<alloc::sync::Arc<str>> for SmallString<N> {
+    #[inline(always)]
+    fn from(s: alloc::sync::Arc<str>) -> Self {
+        Self::new_str(&s)
+    }
+}
+
+impl<const N: usize> From<&alloc::sync::Arc<str>> for SmallString<N> {
+    #[inline(always)]
+    fn from(s: &alloc::sync::Arc<str>) -> Self {
+        Self::new_str(s)
+    }
+}
+
+impl<const N: usize> core::convert::TryFrom<&[u8]> for SmallString<N> {
+    type Error = core::str::Utf8Error;
+
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, core::str::Utf8Error> {
+        core::str::from_utf8(bytes).map(Self::new_str)
+    }
+}
+
+impl<const N: usize> core::convert::TryFrom<&core::ffi::CStr> for SmallString<N> {
+    type Error = core::str::Utf8Error;
+
+    #[inline]
+    fn try_from(text: &core::ffi::CStr) -> Result<Self, core::str::Utf8Error> {
+        text.to_str().map(Self::new_str)
+    }
+}
+
+impl<const N: usize> core::convert::TryFrom<alloc::vec::Vec<u8>> for SmallString<N> {
+    type Error = crate::FromUtf8Error;
+
+    #[inline(always)]
+    fn try_from(bytes: alloc::vec::Vec<u8>) -> Result<Self, crate::FromUtf8Error> {
+        Self::from_utf8(bytes)
+    }
+}
+
+impl<const N: usize> From<fmt::Arguments<'_>> for SmallString<N> {
+    #[inline(always)]
+    fn from(args: fmt::Arguments<'_>) -> Self {
+        Self::from_fmt(args)
+    }
+}
+
+impl<const N: usize> From<SmallString<N>> for alloc::boxed::Box<str> {
+    #[inline(always)]
+    fn from(s: SmallString<N>) -> alloc::boxed::Box<str> {
+        s.into_boxed_str()
+    }
+}
+
+impl<const N: usize> From<&SmallString<N>> for alloc::boxed::Box<str> {
+    #[inline(always)]
+    fn from(s: &SmallString<N>) -> alloc::boxed::Box<str> {
+        alloc::boxed::Box::from(s.as_str())
+    }
+}
+
+impl<const N: usize> From<SmallString<N>> for alloc::rc::Rc<str> {
+    #[inline(always)]
+    fn from(s: SmallString<N>) -> alloc::rc::Rc<str> {
+        alloc::rc::Rc::from(s.as_str())
+    }
+}
+
+impl<const N: usize> From<&SmallString<N>> for alloc::rc::Rc<str> {
+    #[inline(always)]
+    fn from(s: &SmallString<N>) -> alloc::rc::Rc<str> {
+        alloc::rc::Rc::from(s.as_str())
+    }
+}
+
+impl<const N: usize> From<SmallString<N>> for alloc::sync::Arc<str> {
+    #[inline(always)]
+    fn from(s: SmallString<N>) -> alloc::sync::Arc<str> {
+        alloc::sync::Arc::from(s.as_str())
+    }
+}
+
+impl<const N: usize> From<&SmallString<N>> for alloc::sync::Arc<str> {
+    #[inline(always)]
+    fn from(s: &SmallString<N>) -> alloc::sync::Arc<str> {
+        alloc::sync::Arc::from(s.as_str())
+    }
+}
+
+impl<'a, const N: usize> Extend<&'a char> for SmallString<N> {
     #[inline]
     fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
         let iter = iter.into_iter();
@@ -49,7 +170,7 @@ impl<'a> Extend<&'a char> for String {
     }
 }
 
-impl Extend<char> for String {
+impl<const N: usize> Extend<char> for SmallString<N> {
     #[inline]
     fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
         let iter = iter.into_iter();
@@ -61,85 +182,113 @@ impl Extend<char> for String {
     }
 }
 
-impl<'a> Extend<&'a str> for String {
+impl<'a, const N: usize> Extend<&'a str> for SmallString<N> {
     #[inline(always)]
     fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
         iter.into_iter().for_each(move |s| self.push_str(s));
     }
 }
 
-impl Extend<alloc::boxed::Box<str>> for String {
+impl<const N: usize> Extend<alloc::boxed::Box<str>> for SmallString<N> {
     #[inline(always)]
     fn extend<I: IntoIterator<Item = alloc::boxed::Box<str>>>(&mut self, iter: I) {
         iter.into_iter().for_each(move |s| self.push_str(&s));
     }
 }
 
-impl<'a> Extend<alloc::borrow::Cow<'a, str>> for String {
+impl<'a, const N: usize> Extend<alloc::borrow::Cow<'a, str>> for SmallString<N> {
     #[inline(always)]
     fn extend<I: IntoIterator<Item = alloc::borrow::Cow<'a, str>>>(&mut self, iter: I) {
         iter.into_iter().for_each(move |s| self.push_str(&s));
     }
 }
 
-impl Extend<String> for String {
+impl<const N: usize, const M: usize> Extend<SmallString<M>> for SmallString<N> {
     #[inline(always)]
-    fn extend<I: IntoIterator<Item = String>>(&mut self, iter: I) {
+    fn extend<I: IntoIterator<Item = SmallString<M>>>(&mut self, iter: I) {
         iter.into_iter().for_each(move |s| self.push_str(&s));
     }
 }
 
-impl From<alloc::borrow::Cow<'_, str>> for String {
+impl<const N: usize> From<alloc::borrow::Cow<'_, str>> for SmallString<N> {
     #[inline(always)]
-    fn from(s: alloc::borrow::Cow<'_, str>) -> String {
+    fn from(s: alloc::borrow::Cow<'_, str>) -> Self {
         Self::new_str(&s)
     }
 }
 
-impl core::str::FromStr for String {
+impl<const N: usize> From<alloc::string::String> for SmallString<N> {
+    #[inline(always)]
+    fn from(s: alloc::string::String) -> Self {
+        Self::from_std(s)
+    }
+}
+
+impl<const N: usize> From<SmallString<N>> for alloc::string::String {
+    #[inline(always)]
+    fn from(s: SmallString<N>) -> alloc::string::String {
+        s.into_std()
+    }
+}
+
+impl<const N: usize> From<SmallString<N>> for alloc::borrow::Cow<'_, str> {
+    #[inline(always)]
+    fn from(s: SmallString<N>) -> Self {
+        alloc::borrow::Cow::Owned(s.into_std())
+    }
+}
+
+impl<const N: usize> From<SmallString<N>> for alloc::vec::Vec<u8> {
+    #[inline(always)]
+    fn from(s: SmallString<N>) -> alloc::vec::Vec<u8> {
+        s.into_bytes()
+    }
+}
+
+impl<const N: usize> core::str::FromStr for SmallString<N> {
     type Err = core::convert::Infallible;
     #[inline(always)]
-    fn from_str(s: &str) -> Result<String, Self::Err> {
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self::new_str(s))
     }
 }
 
-impl AsRef<[u8]> for String {
+impl<const N: usize> AsRef<[u8]> for SmallString<N> {
     #[inline(always)]
     fn as_ref(&self) -> &[u8] {
         self.as_bytes()
     }
 }
 
-impl AsRef<str> for String {
+impl<const N: usize> AsRef<str> for SmallString<N> {
     #[inline(always)]
     fn as_ref(&self) -> &str {
         self.as_str()
     }
 }
 
-impl AsMut<str> for String {
+impl<const N: usize> AsMut<str> for SmallString<N> {
     #[inline(always)]
     fn as_mut(&mut self) -> &mut str {
         self.as_mut_str()
     }
 }
 
-impl core::borrow::Borrow<str> for String {
+impl<const N: usize> core::borrow::Borrow<str> for SmallString<N> {
     #[inline(always)]
     fn borrow(&self) -> &str {
         self.as_str()
     }
 }
 
-impl core::borrow::BorrowMut<str> for String {
+impl<const N: usize> core::borrow::BorrowMut<str> for SmallString<N> {
     #[inline(always)]
     fn borrow_mut(&mut self) -> &mut str {
         self.as_mut_str()
     }
 }
 
-impl Clone for String {
+impl<const N: usize> Clone for SmallString<N> {
     #[inline(always)]
     fn clone(&self) -> Self {
         match self {
@@ -157,21 +306,21 @@ impl Clone for String {
     }
 }
 
-impl fmt::Debug for String {
+impl<const N: usize> fmt::Debug for SmallString<N> {
     #[inline(always)]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(self.as_str(), f)
     }
 }
 
-impl fmt::Display for String {
+impl<const N: usize> fmt::Display for SmallString<N> {
     #[inline(always)]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Debug::fmt(self.as_str(), f)
+        fmt::Display::fmt(self.as_str(), f)
     }
 }
 
-impl fmt::Write for String {
+impl<const N: usize> fmt::Write for SmallString<N> {
     #[inline(always)]
     fn write_str(&mut self, s: &str) -> fmt::Result {
         self.push_str(s);
@@ -185,22 +334,22 @@ impl fmt::Write for String {
     }
 }
 
-impl hash::Hash for String {
+impl<const N: usize> hash::Hash for SmallString<N> {
     #[inline(always)]
     fn hash<H: hash::Hasher>(&self, hasher: &mut H) {
-        hash::Hash::hash(self.as_str(), hasher)
+        self.hash_str(hasher)
     }
 }
 
-impl Default for String {
+impl<const N: usize> Default for SmallString<N> {
     #[inline(always)]
     /// Creates an empty `String`.
-    fn default() -> String {
+    fn default() -> Self {
         Self::new()
     }
 }
 
-impl core::ops::Deref for String {
+impl<const N: usize> core::ops::Deref for SmallString<N> {
     type Target = str;
 
     #[inline(always)]
@@ -209,80 +358,123 @@ impl core::ops::Deref for String {
     }
 }
 
-impl core::ops::DerefMut for String {
+impl<const N: usize> core::ops::DerefMut for SmallString<N> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut str {
         self.as_mut_str()
     }
 }
 
-impl PartialEq for String {
+impl<const N: usize> PartialEq for SmallString<N> {
     #[inline(always)]
     fn eq(&self, other: &Self) -> bool {
         PartialEq::eq(self.as_str(), other.as_str())
     }
 }
 
-impl PartialEq<str> for String {
+impl<const N: usize> PartialEq<str> for SmallString<N> {
     #[inline(always)]
     fn eq(&self, other: &str) -> bool {
         PartialEq::eq(self.as_str(), other)
     }
 }
 
-impl PartialEq<&str> for String {
+impl<const N: usize> PartialEq<&str> for SmallString<N> {
     #[inline(always)]
     fn eq(&self, other: &&str) -> bool {
         PartialEq::eq(self.as_str(), *other)
     }
 }
 
-impl PartialEq<alloc::string::String> for String {
+impl<const N: usize> PartialEq<alloc::string::String> for SmallString<N> {
     #[inline(always)]
     fn eq(&self, other: &alloc::string::String) -> bool {
         PartialEq::eq(self.as_str(), other.as_str())
     }
 }
 
-impl PartialEq<alloc::borrow::Cow<'_, str>> for String {
+impl<const N: usize> PartialEq<alloc::borrow::Cow<'_, str>> for SmallString<N> {
     #[inline(always)]
     fn eq(&self, other: &alloc::borrow::Cow<'_, str>) -> bool {
         PartialEq::eq(self.as_str(), other)
     }
 }
 
-impl Eq for String {
+impl<const N: usize> Eq for SmallString<N> {
 }
 
-impl PartialEq<String> for &str {
+impl<const N: usize> PartialEq<SmallString<N>> for &str {
     #[inline(always)]
-    fn eq(&self, other: &String) -> bool {
+    fn eq(&self, other: &SmallString<N>) -> bool {
         PartialEq::eq(*self, other.as_str())
     }
 }
 
-impl PartialEq<String> for str {
+impl<const N: usize> PartialEq<SmallString<N>> for str {
     #[inline(always)]
-    fn eq(&self, other: &String) -> bool {
+    fn eq(&self, other: &SmallString<N>) -> bool {
         PartialEq::eq(self, other.as_str())
     }
 }
 
-impl PartialEq<String> for alloc::string::String {
+impl<const N: usize> PartialEq<SmallString<N>> for alloc::string::String {
     #[inline(always)]
-    fn eq(&self, other: &String) -> bool {
+    fn eq(&self, other: &SmallString<N>) -> bool {
         PartialEq::eq(self.as_str(), other.as_str())
     }
 }
 
-impl PartialEq<String> for alloc::borrow::Cow<'_, str> {
+impl<const N: usize> PartialEq<SmallString<N>> for alloc::borrow::Cow<'_, str> {
     #[inline(always)]
-    fn eq(&self, other: &String) -> bool {
+    fn eq(&self, other: &SmallString<N>) -> bool {
         PartialEq::eq(self, other.as_str())
     }
 }
 
-impl core::cmp::PartialOrd for String {
+impl<const N: usize> PartialEq<[u8]> for SmallString<N> {
+    #[inline(always)]
+    fn eq(&self, other: &[u8]) -> bool {
+        self.eq_bytes(other)
+    }
+}
+
+impl<const N: usize> PartialEq<&[u8]> for SmallString<N> {
+    #[inline(always)]
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.eq_bytes(other)
+    }
+}
+
+impl<const N: usize> PartialEq<SmallString<N>> for [u8] {
+    #[inline(always)]
+    fn eq(&self, other: &SmallString<N>) -> bool {
+        other.eq_bytes(self)
+    }
+}
+
+impl<const N: usize> PartialEq<SmallString<N>> for &[u8] {
+    #[inline(always)]
+    fn eq(&self, other: &SmallString<N>) -> bool {
+        other.eq_bytes(self)
+    }
+}
+
+impl<const N: usize> PartialEq<char> for SmallString<N> {
+    #[inline(always)]
+    fn eq(&self, other: &char) -> bool {
+        self.eq_char(*other)
+    }
+}
+
+impl<const N: usize> core::cmp::PartialOrd<char> for SmallString<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &char) -> Option<core::cmp::Ordering> {
+        let mut buf = [0u8; 4];
+        Some(core::cmp::Ord::cmp(self.as_str(), &*other.encode_utf8(&mut buf)))
+    }
+}
+
+impl<const N: usize> core::cmp::PartialOrd for SmallString<N> {
     #[inline(always)]
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(core::cmp::Ord::cmp(self.as_str(), other.as_str()))
@@ -309,18 +501,27 @@ impl core::cmp::PartialOrd for String {
     }
 }
 
-impl core::cmp::Ord for String {
+impl<const N: usize> core::cmp::Ord for SmallString<N> {
     #[inline(always)]
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         core::cmp::Ord::cmp(self.as_str(), other.as_str())
     }
 }
 
-impl<'a> core::iter::FromIterator<&'a char> for String {
+impl<const N: usize> core::iter::FromIterator<char> for SmallString<N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut res = Self::new();
+        res.extend(iter);
+        res
+    }
+}
+
+impl<'a, const N: usize> core::iter::FromIterator<&'a char> for SmallString<N> {
     #[inline]
-    fn from_iter<I: IntoIterator<Item = &'a char>>(iter: I) -> String {
+    fn from_iter<I: IntoIterator<Item = &'a char>>(iter: I) -> Self {
         let mut buf = [0u8; 4];
-        let mut res = String::new();
+        let mut res = Self::new();
         for ch in iter {
             res.push_str(ch.encode_utf8(&mut buf));
         }
@@ -328,10 +529,10 @@ impl<'a> core::iter::FromIterator<&'a char> for String {
     }
 }
 
-impl<'a> core::iter::FromIterator<&'a str> for String {
+impl<'a, const N: usize> core::iter::FromIterator<&'a str> for SmallString<N> {
     #[inline]
-    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> String {
-        let mut res = String::new();
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut res = Self::new();
         for text in iter {
             res.push_str(text)
         }
@@ -339,10 +540,21 @@ impl<'a> core::iter::FromIterator<&'a str> for String {
     }
 }
 
-impl core::iter::FromIterator<alloc::boxed::Box<str>> for String {
+impl<const N: usize> core::iter::FromIterator<alloc::boxed::Box<str>> for SmallString<N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = alloc::boxed::Box<str>>>(iter: I) -> Self {
+        let mut res = Self::new();
+        for text in iter {
+            res.push_str(&text)
+        }
+        res
+    }
+}
+
+impl<'a, const N: usize> core::iter::FromIterator<alloc::borrow::Cow<'a, str>> for SmallString<N> {
     #[inline]
-    fn from_iter<I: IntoIterator<Item = alloc::boxed::Box<str>>>(iter: I) -> String {
-        let mut res = String::new();
+    fn from_iter<I: IntoIterator<Item = alloc::borrow::Cow<'a, str>>>(iter: I) -> Self {
+        let mut res = Self::new();
         for text in iter {
             res.push_str(&text)
         }
@@ -350,10 +562,10 @@ impl core::iter::FromIterator<alloc::boxed::Box<str>> for String {
     }
 }
 
-impl core::iter::FromIterator<alloc::string::String> for String {
+impl<const N: usize> core::iter::FromIterator<alloc::string::String> for SmallString<N> {
     #[inline]
-    fn from_iter<I: IntoIterator<Item = alloc::string::String>>(iter: I) -> String {
-        let mut res = String::new();
+    fn from_iter<I: IntoIterator<Item = alloc::string::String>>(iter: I) -> Self {
+        let mut res = Self::new();
         for text in iter {
             res.push_str(&text)
         }
@@ -361,13 +573,13 @@ impl core::iter::FromIterator<alloc::string::String> for String {
     }
 }
 
-impl core::iter::FromIterator<String> for String {
+impl<const N: usize> core::iter::FromIterator<SmallString<N>> for SmallString<N> {
     #[inline]
-    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> String {
+    fn from_iter<I: IntoIterator<Item = SmallString<N>>>(iter: I) -> Self {
         let mut iter = iter.into_iter();
 
         match iter.next() {
-            None => String::new(),
+            None => Self::new(),
             Some(mut res) => {
                 for text in iter {
                     res.push_str(&text)
@@ -378,24 +590,94 @@ impl core::iter::FromIterator<String> for String {
     }
 }
 
-impl core::ops::Add<&str> for String {
-    type Output = String;
+impl<const N: usize> core::ops::Add<&str> for SmallString<N> {
+    type Output = Self;
 
     #[inline(always)]
-    fn add(mut self, other: &str) -> String {
+    fn add(mut self, other: &str) -> Self {
         self.push_str(other);
         self
     }
 }
 
-impl core::ops::AddAssign<&str> for String {
+impl<const N: usize> core::ops::AddAssign<&str> for SmallString<N> {
     #[inline(always)]
     fn add_assign(&mut self, other: &str) {
         self.push_str(other);
     }
 }
 
-impl core::ops::Index<core::ops::Range<usize>> for String {
+impl<const N: usize> core::ops::Add<char> for SmallString<N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(mut self, other: char) -> Self {
+        self.push(other);
+        self
+    }
+}
+
+impl<const N: usize> core::ops::AddAssign<char> for SmallString<N> {
+    #[inline(always)]
+    fn add_assign(&mut self, other: char) {
+        self.push(other);
+    }
+}
+
+impl<const N: usize, const M: usize> core::ops::Add<&SmallString<M>> for SmallString<N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(mut self, other: &SmallString<M>) -> Self {
+        self.push_str(other.as_str());
+        self
+    }
+}
+
+impl<const N: usize, const M: usize> core::ops::AddAssign<&SmallString<M>> for SmallString<N> {
+    #[inline(always)]
+    fn add_assign(&mut self, other: &SmallString<M>) {
+        self.push_str(other.as_str());
+    }
+}
+
+//With multiple `Add` impls, right hand side is no longer coerced to `&str`, hence common owners of `str` are listed explicitly.
+macro_rules! impl_add_str_ref {
+    ($($typ:ty),+) => {
+        $(
+            impl<const N: usize> core::ops::Add<&$typ> for SmallString<N> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn add(mut self, other: &$typ) -> Self {
+                    self.push_str(other);
+                    self
+                }
+            }
+
+            impl<const N: usize> core::ops::AddAssign<&$typ> for SmallString<N> {
+                #[inline(always)]
+                fn add_assign(&mut self, other: &$typ) {
+                    self.push_str(other);
+                }
+            }
+        )+
+    }
+}
+
+impl_add_str_ref!(&str, alloc::string::String, alloc::boxed::Box<str>, alloc::borrow::Cow<'_, str>, alloc::rc::Rc<str>, alloc::sync::Arc<str>);
+
+impl<const N: usize> core::ops::Add<alloc::borrow::Cow<'_, str>> for SmallString<N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(mut self, other: alloc::borrow::Cow<'_, str>) -> Self {
+        self.push_str(&other);
+        self
+    }
+}
+
+impl<const N: usize> core::ops::Index<core::ops::Range<usize>> for SmallString<N> {
     type Output = str;
 
     #[inline(always)]
@@ -404,7 +686,7 @@ impl core::ops::Index<core::ops::Range<usize>> for String {
     }
 }
 
-impl core::ops::Index<core::ops::RangeTo<usize>> for String {
+impl<const N: usize> core::ops::Index<core::ops::RangeTo<usize>> for SmallString<N> {
     type Output = str;
 
     #[inline(always)]
@@ -413,7 +695,7 @@ impl core::ops::Index<core::ops::RangeTo<usize>> for String {
     }
 }
 
-impl core::ops::Index<core::ops::RangeFrom<usize>> for String {
+impl<const N: usize> core::ops::Index<core::ops::RangeFrom<usize>> for SmallString<N> {
     type Output = str;
 
     #[inline(always)]
@@ -422,7 +704,7 @@ impl core::ops::Index<core::ops::RangeFrom<usize>> for String {
     }
 }
 
-impl core::ops::Index<core::ops::RangeFull> for String {
+impl<const N: usize> core::ops::Index<core::ops::RangeFull> for SmallString<N> {
     type Output = str;
 
     #[inline(always)]
@@ -431,7 +713,7 @@ impl core::ops::Index<core::ops::RangeFull> for String {
     }
 }
 
-impl core::ops::Index<core::ops::RangeInclusive<usize>> for String {
+impl<const N: usize> core::ops::Index<core::ops::RangeInclusive<usize>> for SmallString<N> {
     type Output = str;
 
     #[inline(always)]
@@ -440,7 +722,7 @@ impl core::ops::Index<core::ops::RangeInclusive<usize>> for String {
     }
 }
 
-impl core::ops::Index<core::ops::RangeToInclusive<usize>> for String {
+impl<const N: usize> core::ops::Index<core::ops::RangeToInclusive<usize>> for SmallString<N> {
     type Output = str;
 
     #[inline(always)]
@@ -449,42 +731,42 @@ impl core::ops::Index<core::ops::RangeToInclusive<usize>> for String {
     }
 }
 
-impl core::ops::IndexMut<core::ops::Range<usize>> for String {
+impl<const N: usize> core::ops::IndexMut<core::ops::Range<usize>> for SmallString<N> {
     #[inline(always)]
     fn index_mut(&mut self, index: core::ops::Range<usize>) -> &mut str {
         core::ops::IndexMut::index_mut(self.as_mut_str(), index)
     }
 }
 
-impl core::ops::IndexMut<core::ops::RangeTo<usize>> for String {
+impl<const N: usize> core::ops::IndexMut<core::ops::RangeTo<usize>> for SmallString<N> {
     #[inline(always)]
     fn index_mut(&mut self, index: core::ops::RangeTo<usize>) -> &mut str {
         core::ops::IndexMut::index_mut(self.as_mut_str(), index)
     }
 }
 
-impl core::ops::IndexMut<core::ops::RangeFrom<usize>> for String {
+impl<const N: usize> core::ops::IndexMut<core::ops::RangeFrom<usize>> for SmallString<N> {
     #[inline(always)]
     fn index_mut(&mut self, index: core::ops::RangeFrom<usize>) -> &mut str {
         core::ops::IndexMut::index_mut(self.as_mut_str(), index)
     }
 }
 
-impl core::ops::IndexMut<core::ops::RangeFull> for String {
+impl<const N: usize> core::ops::IndexMut<core::ops::RangeFull> for SmallString<N> {
     #[inline(always)]
     fn index_mut(&mut self, _: core::ops::RangeFull) -> &mut str {
         self.as_mut_str()
     }
 }
 
-impl core::ops::IndexMut<core::ops::RangeInclusive<usize>> for String {
+impl<const N: usize> core::ops::IndexMut<core::ops::RangeInclusive<usize>> for SmallString<N> {
     #[inline(always)]
     fn index_mut(&mut self, index: core::ops::RangeInclusive<usize>) -> &mut str {
         core::ops::IndexMut::index_mut(self.as_mut_str(), index)
     }
 }
 
-impl core::ops::IndexMut<core::ops::RangeToInclusive<usize>> for String {
+impl<const N: usize> core::ops::IndexMut<core::ops::RangeToInclusive<usize>> for SmallString<N> {
     #[inline(always)]
     fn index_mut(&mut self, index: core::ops::RangeToInclusive<usize>) -> &mut str {
         core::ops::IndexMut::index_mut(self.as_mut_str(), index)
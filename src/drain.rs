@@ -1,17 +1,17 @@
-use crate::String;
+use crate::{SmallString, SSO_MAX_SIZE};
 use core::{fmt, ptr};
 use core::str::Chars;
 use core::iter::{FusedIterator, DoubleEndedIterator};
 
 ///Draining iterator over `String`
-pub struct Drain<'a> {
-    pub(crate) string: *mut String,
+pub struct Drain<'a, const N: usize = SSO_MAX_SIZE> {
+    pub(crate) string: *mut SmallString<N>,
     pub(crate) start: usize,
     pub(crate) end: usize,
     pub(crate) chars: Chars<'a>
 }
 
-impl<'a> Drain<'a> {
+impl<'a, const N: usize> Drain<'a, N> {
     #[inline]
     ///Returns the remaining sub-string of this iterator.
     pub fn as_str(&self) -> &str {
@@ -19,7 +19,7 @@ impl<'a> Drain<'a> {
     }
 }
 
-impl Iterator for Drain<'_> {
+impl<const N: usize> Iterator for Drain<'_, N> {
     type Item = char;
 
     #[inline(always)]
@@ -38,16 +38,16 @@ impl Iterator for Drain<'_> {
     }
 }
 
-impl DoubleEndedIterator for Drain<'_> {
+impl<const N: usize> DoubleEndedIterator for Drain<'_, N> {
     #[inline(always)]
     fn next_back(&mut self) -> Option<char> {
         self.chars.next_back()
     }
 }
 
-impl FusedIterator for Drain<'_> {}
+impl<const N: usize> FusedIterator for Drain<'_, N> {}
 
-impl<'a> Drop for Drain<'a> {
+impl<'a, const N: usize> Drop for Drain<'a, N> {
     fn drop(&mut self) {
         let this = unsafe {
             &mut *(self.string)
@@ -55,28 +55,28 @@ impl<'a> Drop for Drain<'a> {
 
         let range_size = self.end - self.start;
         match this {
-            String::Heap(ref mut heap) => {
+            SmallString::Heap(ref mut heap) => {
                 unsafe {
                     ptr::copy(heap.as_ptr().add(self.end), heap.as_mut_ptr().add(self.start), heap.len() - self.start - range_size);
                     heap.set_len(heap.len() - range_size);
                 }
             },
-            String::Sso(ref mut sso) => {
+            SmallString::Sso(ref mut sso) => {
                 unsafe {
                     ptr::copy(sso.as_ptr().add(self.end), sso.as_mut_ptr().add(self.start), sso.len() - self.start - range_size);
-                    sso.set_len(sso.len() as u8 - range_size as u8);
+                    crate::set_sso_len(sso, sso.len() - range_size);
                 }
             },
         }
     }
 }
 
-impl fmt::Debug for Drain<'_> {
+impl<const N: usize> fmt::Debug for Drain<'_, N> {
     #[inline(always)]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("Drain").field(&self.as_str()).finish()
     }
 }
 
-unsafe impl Sync for Drain<'_> {}
-unsafe impl Send for Drain<'_> {}
+unsafe impl<const N: usize> Sync for Drain<'_, N> {}
+unsafe impl<const N: usize> Send for Drain<'_, N> {}
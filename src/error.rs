@@ -0,0 +1,263 @@
+use core::fmt;
+
+///Error indicating invalid unicode scalar value within UTF-32 sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Utf32Error {
+    pub(crate) index: usize,
+    pub(crate) value: u32,
+}
+
+impl Utf32Error {
+    #[inline(always)]
+    ///Returns index of the invalid value within input.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    #[inline(always)]
+    ///Returns invalid value itself.
+    pub const fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+impl fmt::Display for Utf32Error {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid unicode scalar value {:#x} at index {}", self.value, self.index)
+    }
+}
+
+///Error indicating failure to allocate memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryReserveError {
+    ///Requested capacity exceeds maximum possible capacity.
+    CapacityOverflow,
+    ///Memory allocator failed to allocate memory.
+    AllocError {
+        ///Layout of the failed allocation.
+        layout: core::alloc::Layout,
+    },
+}
+
+impl From<minivec::TryReserveError> for TryReserveError {
+    #[inline]
+    fn from(error: minivec::TryReserveError) -> Self {
+        match error.kind() {
+            minivec::TryReserveErrorKind::CapacityOverflow => Self::CapacityOverflow,
+            minivec::TryReserveErrorKind::AllocError { layout } => Self::AllocError {
+                layout,
+            },
+        }
+    }
+}
+
+impl fmt::Display for TryReserveError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CapacityOverflow => f.write_str("memory allocation failed because the computed capacity exceeded the collection's maximum"),
+            Self::AllocError { .. } => f.write_str("memory allocation failed because the memory allocator returned an error"),
+        }
+    }
+}
+
+///Error indicating invalid index within string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundaryError {
+    ///Index is beyond string's length.
+    OutOfBounds {
+        ///Invalid index.
+        index: usize,
+        ///Length of the string.
+        len: usize,
+    },
+    ///Index lies within a character.
+    NotCharBoundary {
+        ///Invalid index.
+        index: usize,
+    },
+}
+
+impl BoundaryError {
+    #[inline(always)]
+    ///Returns invalid index.
+    pub const fn index(&self) -> usize {
+        match self {
+            Self::OutOfBounds { index, .. } => *index,
+            Self::NotCharBoundary { index } => *index,
+        }
+    }
+}
+
+impl fmt::Display for BoundaryError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds { index, len } => write!(f, "byte index {} is out of bounds of string with length {}", index, len),
+            Self::NotCharBoundary { index } => write!(f, "byte index {} is not a char boundary", index),
+        }
+    }
+}
+
+///Error indicating invalid range within string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeError {
+    ///Start of the range is invalid.
+    Start(BoundaryError),
+    ///End of the range is invalid.
+    End(BoundaryError),
+    ///Start of the range is greater than its end.
+    StartAfterEnd {
+        ///Start of the range.
+        start: usize,
+        ///End of the range.
+        end: usize,
+    },
+}
+
+impl fmt::Display for RangeError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Start(error) => write!(f, "invalid range start: {}", error),
+            Self::End(error) => write!(f, "invalid range end: {}", error),
+            Self::StartAfterEnd { start, end } => write!(f, "start '{}' is greater than end '{}'", start, end),
+        }
+    }
+}
+
+///Error indicating invalid UTF-8 within bytes passed to `String::from_utf8`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FromUtf8Error {
+    pub(crate) bytes: alloc::vec::Vec<u8>,
+    pub(crate) error: core::str::Utf8Error,
+}
+
+impl FromUtf8Error {
+    #[inline(always)]
+    ///Returns slice of bytes that were attempted to convert.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    #[inline(always)]
+    ///Returns bytes that were attempted to convert.
+    pub fn into_bytes(self) -> alloc::vec::Vec<u8> {
+        self.bytes
+    }
+
+    #[inline(always)]
+    ///Returns underlying UTF-8 error.
+    pub fn utf8_error(&self) -> core::str::Utf8Error {
+        self.error
+    }
+}
+
+impl fmt::Display for FromUtf8Error {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+///Error indicating invalid UTF-16 within bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromUtf16Error {
+    ///Number of bytes is odd, hence last byte is not part of any code unit.
+    OddLength,
+    ///Bytes contain invalid UTF-16 sequence.
+    Decode(core::char::DecodeUtf16Error),
+}
+
+impl fmt::Display for FromUtf16Error {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OddLength => f.write_str("invalid utf-16: odd number of bytes"),
+            Self::Decode(error) => fmt::Display::fmt(error, f),
+        }
+    }
+}
+
+///Error indicating non-ASCII byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AsciiError {
+    pub(crate) index: usize,
+    pub(crate) byte: u8,
+}
+
+impl AsciiError {
+    #[inline(always)]
+    ///Returns index of non-ASCII byte within input.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    #[inline(always)]
+    ///Returns non-ASCII byte.
+    pub const fn byte(&self) -> u8 {
+        self.byte
+    }
+}
+
+impl fmt::Display for AsciiError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "non-ASCII byte 0x{:02X} at index {}", self.byte, self.index)
+    }
+}
+
+///Error indicating incomplete UTF-8 character at the end of input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IncompleteUtf8Error {
+    pub(crate) bytes: [u8; 4],
+    pub(crate) len: u8,
+}
+
+impl IncompleteUtf8Error {
+    #[inline(always)]
+    ///Returns bytes of incomplete character.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len as usize]
+    }
+}
+
+impl fmt::Display for IncompleteUtf8Error {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "incomplete utf-8 byte sequence of {} bytes at the end of input", self.len)
+    }
+}
+
+///Error indicating empty string, where non-empty one is required.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmptyError;
+
+impl fmt::Display for EmptyError {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("string must not be empty")
+    }
+}
+
+///Error indicating that string's capacity is insufficient without allocation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError {
+    pub(crate) missing: usize,
+}
+
+impl CapacityError {
+    #[inline(always)]
+    ///Returns number of bytes missing from capacity.
+    pub const fn missing(&self) -> usize {
+        self.missing
+    }
+}
+
+impl fmt::Display for CapacityError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "insufficient capacity, missing {} bytes", self.missing)
+    }
+}
@@ -0,0 +1,90 @@
+//! C ABI helpers.
+//!
+//! Provides `StrView` as stable representation of borrowed string, to be passed across FFI boundary.
+//!
+//! ## Lifetime
+//!
+//! View does not own content, it only points to storage of string it was created from.
+//! Therefore it is invalidated by any mutation of string or when string is dropped.
+//! Additionally, inline (SSO) string stores content within itself, so moving such string
+//! invalidates its views too, even if content is not modified.
+//!
+//! ## Example
+//!
+//!```
+//!use stroka::ffi::StrView;
+//!
+//!type Callback = extern "C" fn(name: StrView, user_data: *mut core::ffi::c_void) -> bool;
+//!
+//!extern "C" fn plugin(name: StrView, _user_data: *mut core::ffi::c_void) -> bool {
+//!    //Copy content, as view is only valid for the duration of call
+//!    match unsafe { stroka::String::from_view_copy(name) } {
+//!        Ok(name) => name == "lolka",
+//!        Err(_) => false,
+//!    }
+//!}
+//!
+//!let callback: Callback = plugin;
+//!let name = stroka::String::new_str("lolka");
+//!assert!(callback(name.as_view(), core::ptr::null_mut()));
+//!```
+
+use crate::{SmallString, FromUtf8Error};
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+///Borrowed view of string content with C compatible layout.
+///
+///Corresponds to C struct `{ const uint8_t* ptr; size_t len; }`.
+///Content is not NUL terminated.
+pub struct StrView {
+    ///Pointer to the first byte of content.
+    pub ptr: *const u8,
+    ///Length of content in bytes.
+    pub len: usize,
+}
+
+impl<const N: usize> SmallString<N> {
+    #[inline(always)]
+    ///Returns C compatible view of string content.
+    ///
+    ///View is invalidated by any mutation, move of inline string or drop of string.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn as_view(&self) -> StrView {
+        StrView {
+            ptr: self.as_ptr(),
+            len: self.len(),
+        }
+    }
+
+    ///Creates string by copying content of `view`, validating that it is UTF-8.
+    ///
+    ///Empty view is accepted regardless of its pointer, including NULL.
+    ///
+    ///In case of invalid UTF-8, returns `FromUtf8Error` containing copy of bytes.
+    ///
+    ///## Safety
+    ///
+    ///Unless `view.len` is `0`, `view.ptr` must be valid for reads of `view.len` bytes.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub unsafe fn from_view_copy(view: StrView) -> Result<Self, FromUtf8Error> {
+        if view.len == 0 {
+            return Ok(Self::new());
+        }
+
+        let bytes = core::slice::from_raw_parts(view.ptr, view.len);
+        match core::str::from_utf8(bytes) {
+            Ok(text) => Ok(Self::new_str(text)),
+            Err(error) => Err(FromUtf8Error {
+                bytes: bytes.to_vec(),
+                error,
+            }),
+        }
+    }
+}
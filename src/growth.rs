@@ -0,0 +1,57 @@
+//! Tuning knobs of heap growth policy.
+//!
+//! Intended for benchmarking alternative policies without patching crate.
+//! Settings are process-global and consulted whenever inline string is moved onto heap.
+//! Statistics of these moves are collected as well, see `growth_stats`.
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+static MIN_HEAP_CAPACITY: AtomicUsize = AtomicUsize::new(0);
+
+#[inline]
+///Sets minimum capacity allocated when inline string is moved onto heap.
+///
+///`0` restores built-in policy, which allocates only what is requested.
+pub fn set_min_heap_capacity(capacity: usize) {
+    MIN_HEAP_CAPACITY.store(capacity, Ordering::Relaxed);
+}
+
+#[inline]
+///Returns minimum capacity allocated when inline string is moved onto heap.
+pub fn min_heap_capacity() -> usize {
+    MIN_HEAP_CAPACITY.load(Ordering::Relaxed)
+}
+
+static PROMOTIONS: AtomicUsize = AtomicUsize::new(0);
+static PROMOTED_CAPACITY: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+///Statistics of inline strings moved onto heap, collected since start or last reset.
+pub struct GrowthStats {
+    ///Number of times inline string was moved onto heap.
+    pub promotions: usize,
+    ///Total capacity of heap allocations made by these promotions.
+    pub promoted_capacity: usize,
+}
+
+#[inline]
+///Returns statistics collected across all strings since start or last `reset_growth_stats`.
+pub fn growth_stats() -> GrowthStats {
+    GrowthStats {
+        promotions: PROMOTIONS.load(Ordering::Relaxed),
+        promoted_capacity: PROMOTED_CAPACITY.load(Ordering::Relaxed),
+    }
+}
+
+#[inline]
+///Resets statistics returned by `growth_stats`.
+pub fn reset_growth_stats() {
+    PROMOTIONS.store(0, Ordering::Relaxed);
+    PROMOTED_CAPACITY.store(0, Ordering::Relaxed);
+}
+
+#[inline]
+pub(crate) fn record_promotion(capacity: usize) {
+    PROMOTIONS.fetch_add(1, Ordering::Relaxed);
+    PROMOTED_CAPACITY.fetch_add(capacity, Ordering::Relaxed);
+}
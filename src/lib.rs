@@ -1,17 +1,21 @@
 //! `String` implementation optimized for small sized strings(at most length `mem::size_of::<usize>() * 2 - 2`)
 //!
+//! `SmallString<N>` provides the same implementation with user chosen size of static buffer.
+//!
 //! ## Features
 //!
 //! - `serde` - Enables `Serialize` and `Deserialize` implementations.
 //! - `std` - Enables traits implementations dependent on `std`.
+//! - `base64` - Enables base64 encoding and decoding helpers.
+//! - `paranoid` - Validates UTF-8 of heap storage on every access, making it O(n).
+//! - `sso-only` - Forbids heap allocation. Any operation that would move string onto heap panics instead.
+//! - `tuning` - Enables `growth` module with process-global knobs of heap growth policy, intended for benchmarking.
+//! - `ffi` - Enables `ffi` module with C compatible string view.
 //!
 //! ## Missing functions
 //!
-//! - `String::from_utf8` - due to `minivec` yet to be stable.
-//! - `String::from_utf8_unchecked` - due to `minivec` yet to be stable.
-//! - `String::into_bytes` - due to `minivec` yet to be stable.
 //! - Unstable functions of String - due to them being potentially changed.
-//! - `String::from_raw_parts` - cannot be implemented due to internal structure.
+//! - `String::from_raw_parts` - cannot be implemented due to internal structure. Use `into_raw_parts` and `from_heap_parts` instead.
 
 #![no_std]
 #![warn(missing_docs)]
@@ -20,7 +24,7 @@
 extern crate alloc;
 
 #[cfg(feature = "serde")]
-mod serde;
+pub mod serde;
 #[cfg(feature = "std")]
 mod std;
 mod core_traits;
@@ -30,12 +34,35 @@ mod utils;
 use utils::MiniStr;
 mod drain;
 pub use drain::Drain;
+mod lines;
+pub use lines::IntoLines;
+pub mod pool;
+pub mod types;
+#[cfg(feature = "tuning")]
+pub mod growth;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod chars;
+pub use chars::{IntoChars, CharBoundaries};
+mod ascii;
+pub use ascii::AsciiBytesMut;
+mod lossy;
+mod appender;
+pub use appender::Utf8Appender;
+pub use lossy::{lossy, Lossy, utf8_chunks, Utf8Chunks, Utf8Chunk};
+mod error;
+pub use error::{Utf32Error, TryReserveError, BoundaryError, RangeError, FromUtf8Error, FromUtf16Error, AsciiError, IncompleteUtf8Error, EmptyError, CapacityError};
+#[cfg(feature = "base64")]
+mod base64;
+#[cfg(feature = "base64")]
+pub use base64::{Base64Config, Base64DecodeError};
 
 use core::{ptr, mem};
+use core::ops::Bound;
 
 type HeapStr = minivec::MiniVec<u8>;
 const SSO_MAX_SIZE: usize = mem::size_of::<HeapStr>() * 2 - 2;
-type StrBuf = str_buf::StrBuf<{SSO_MAX_SIZE}>;
+type StrBuf<const N: usize> = str_buf::StrBuf<N>;
 
 #[inline(always)]
 unsafe fn insert_bytes_into(ptr: *mut u8, len: usize, idx: usize, bytes: &[u8]) {
@@ -44,38 +71,206 @@ unsafe fn insert_bytes_into(ptr: *mut u8, len: usize, idx: usize, bytes: &[u8])
     ptr::copy(bytes.as_ptr(), ptr.add(idx), bytes_len);
 }
 
-//verifies validity of range and returns its length
-fn assert_range_len(this: &str, start: core::ops::Bound<&usize>, end: core::ops::Bound<&usize>) -> (usize, usize, usize) {
+#[inline(always)]
+///Sets length of inline buffer, checking in debug builds that it fits into `u8` storage.
+unsafe fn set_sso_len<const N: usize>(sso: &mut StrBuf<N>, new_len: usize) {
+    debug_assert!(new_len <= StrBuf::<N>::capacity(), "SSO length {} exceeds inline capacity {}", new_len, StrBuf::<N>::capacity());
+    sso.set_len(new_len as u8);
+}
+
+#[cfg(feature = "sso-only")]
+#[cold]
+#[inline(never)]
+fn heap_forbidden(capacity: usize, inline_capacity: usize) -> ! {
+    panic!("capacity {} exceeds inline capacity {} while heap is forbidden by 'sso-only' feature", capacity, inline_capacity)
+}
+
+#[inline(always)]
+//Allocates heap storage for string with inline capacity `N`, which is the only way to construct heap variant.
+fn heap_with_capacity<const N: usize>(capacity: usize) -> HeapStr {
+    #[cfg(feature = "sso-only")]
+    {
+        heap_forbidden(capacity, N)
+    }
+    #[cfg(not(feature = "sso-only"))]
+    {
+        HeapStr::with_capacity(capacity)
+    }
+}
+
+#[inline(always)]
+//Applies tuning knobs to capacity of inline string being moved onto heap.
+fn promotion_capacity(capacity: usize) -> usize {
+    #[cfg(feature = "tuning")]
+    {
+        core::cmp::max(capacity, growth::min_heap_capacity())
+    }
+    #[cfg(not(feature = "tuning"))]
+    {
+        capacity
+    }
+}
+
+#[inline(always)]
+//Records heap storage of inline string that has just been moved onto heap.
+fn record_promotion(_heap: &HeapStr) {
+    #[cfg(feature = "tuning")]
+    growth::record_promotion(_heap.capacity());
+}
+
+//Fallible counterpart of `heap_with_capacity`.
+fn try_heap_with_capacity(capacity: usize) -> Result<HeapStr, TryReserveError> {
+    #[cfg(feature = "sso-only")]
+    {
+        let _ = capacity;
+        Err(TryReserveError::CapacityOverflow)
+    }
+    #[cfg(not(feature = "sso-only"))]
+    {
+        let mut heap = HeapStr::new();
+        heap.try_reserve_exact(capacity)?;
+        Ok(heap)
+    }
+}
+
+//Capacity beyond which growth is rounded to multiple of 16 instead of 8.
+const GROWTH_ROUND_THRESHOLD: usize = 128;
+
+#[inline(always)]
+//Rounds capacity up to allocator's typical size class.
+//
+//Allocators hand out blocks in multiples of 8 or 16 bytes anyway, so rounding makes these bytes visible via `capacity()`.
+//Capacity is left as it is if rounding would exceed `isize::MAX`.
+fn round_capacity(capacity: usize) -> usize {
+    let align = if capacity > GROWTH_ROUND_THRESHOLD {
+        16
+    } else {
+        8
+    };
+    match capacity.checked_add(align - 1) {
+        Some(rounded) if rounded & !(align - 1) <= isize::MAX as usize => rounded & !(align - 1),
+        _ => capacity,
+    }
+}
+
+#[inline(always)]
+//Computes capacity heap storage grows to, when it needs to store `required` bytes.
+//
+//Capacity is at least doubled in order to amortize cost of reallocations.
+fn growth_capacity(capacity: usize, required: usize) -> usize {
+    let doubled = capacity.saturating_mul(2);
+    if doubled > required && doubled <= isize::MAX as usize {
+        round_capacity(doubled)
+    } else {
+        round_capacity(required)
+    }
+}
+
+#[cold]
+#[inline(never)]
+//Grows heap storage, kept out of line from hot paths.
+fn grow_heap(heap: &mut HeapStr, additional: usize) {
+    let len = heap.len();
+    let capacity = growth_capacity(heap.capacity(), required_capacity(len, additional));
+    heap.reserve_exact(capacity - len);
+}
+
+#[cold]
+#[inline(never)]
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
+
+#[inline(always)]
+//Computes capacity required to store `additional` bytes more, panicking on overflow as std does.
+fn required_capacity(len: usize, additional: usize) -> usize {
+    match len.checked_add(additional) {
+        Some(required) if required <= isize::MAX as usize => required,
+        _ => capacity_overflow(),
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn boundary_fail(idx: usize, len: usize) -> ! {
+    panic!("byte index {} is not a char boundary or out of bounds of string with length {}", idx, len)
+}
+
+#[inline(always)]
+fn assert_char_boundary(this: &str, idx: usize) {
+    if !this.is_char_boundary(idx) {
+        boundary_fail(idx, this.len())
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn range_order_fail(start: usize, end: usize) -> ! {
+    panic!("start '{}' is greater than end '{}'", start, end)
+}
+
+#[inline(always)]
+fn range_bounds<R: core::ops::RangeBounds<usize>>(range: &R) -> (Bound<usize>, Bound<usize>) {
+    (range.start_bound().cloned(), range.end_bound().cloned())
+}
+
+#[inline]
+fn check_char_boundary(this: &str, index: usize) -> Result<(), BoundaryError> {
+    if index > this.len() {
+        Err(BoundaryError::OutOfBounds {
+            index,
+            len: this.len(),
+        })
+    } else if !this.is_char_boundary(index) {
+        Err(BoundaryError::NotCharBoundary {
+            index,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+//verifies validity of range and returns its start and end
+fn check_range(this: &str, start: Bound<usize>, end: Bound<usize>) -> Result<(usize, usize), RangeError> {
     let start = match start {
-        core::ops::Bound::Included(n) => {
-            assert!(this.is_char_boundary(*n));
-            *n
-        },
-        core::ops::Bound::Excluded(n) => {
-            let n = n.saturating_add(1);
-            assert!(this.is_char_boundary(n));
-            n
-        },
-        core::ops::Bound::Unbounded => 0,
+        Bound::Included(n) => n,
+        Bound::Excluded(n) => n.saturating_add(1),
+        Bound::Unbounded => 0,
     };
     let end = match end {
-        core::ops::Bound::Included(n) => {
-            let n = n.saturating_add(1);
-            assert!(this.is_char_boundary(n));
-            n
-        },
-        core::ops::Bound::Excluded(n) => {
-            assert!(this.is_char_boundary(*n));
-            *n
-        },
-        core::ops::Bound::Unbounded => this.len()
+        Bound::Included(n) => n.saturating_add(1),
+        Bound::Excluded(n) => n,
+        Bound::Unbounded => this.len()
     };
 
+    check_char_boundary(this, start).map_err(RangeError::Start)?;
+    check_char_boundary(this, end).map_err(RangeError::End)?;
+
     if start > end {
-        panic!("start '{}' is greater than end '{}'", start, end);
+        Err(RangeError::StartAfterEnd {
+            start,
+            end,
+        })
+    } else {
+        Ok((start, end))
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn range_fail(len: usize, error: RangeError) -> ! {
+    match error {
+        RangeError::Start(error) | RangeError::End(error) => boundary_fail(error.index(), len),
+        RangeError::StartAfterEnd { start, end } => range_order_fail(start, end),
     }
+}
 
-    (start, end, end - start)
+//verifies validity of range and returns its length
+fn assert_range_len(this: &str, start: Bound<usize>, end: Bound<usize>) -> (usize, usize, usize) {
+    match check_range(this, start, end) {
+        Ok((start, end)) => (start, end, end - start),
+        Err(error) => range_fail(this.len(), error),
+    }
 }
 
 ///`String`, similar to that in `std`, but optimized with SSO (small string optimization).
@@ -88,19 +283,135 @@ fn assert_range_len(this: &str, start: core::ops::Bound<&usize>, end: core::ops:
 ///For obvious reasons 32bit targets have smaller buffer size of `6` bytes.
 ///
 ///When string's content overflows static buffer, its content is moved onto heap.
-///Clearing/shrinking capacity will no longer switch back at this point.
-pub enum String {
+///Clearing capacity will no longer switch back at this point, but shrinking does move content,
+///that fits static buffer, back inline, freeing allocation.
+///
+///## Layout
+///
+///Heap variant is a single pointer (`MiniVec`), which stores length and capacity in its allocation header.
+///Hence accessors like `len()` or `as_bytes()` have to select variant first, as heap length cannot be read without
+///dereferencing pointer, which is invalid for inline variant.
+///Branch-free layouts (e.g. `compact_str`) keep both pointer and length inline, which requires at least 3 words
+///or giving up on `MiniVec` and is not compatible with 2 words size guarantee.
+///
+///Variant discriminant occupies its own byte, leaving unused values as niche.
+///Hence `Option<String>` (and nested `Option`) is guaranteed to be 2 words too.
+///
+///For different size of static buffer, see `SmallString`.
+pub type String = SmallString<SSO_MAX_SIZE>;
+
+///String with static buffer of `N` bytes, moving its content onto heap when it overflows.
+///
+///`String` is `SmallString` with buffer size, that keeps it within 2 words.
+///Other sizes trade memory for ability to keep longer strings inline (e.g. `SmallString<22>` takes 3 words).
+///
+///`N` cannot exceed `255`, as length of inline content is stored in a single byte.
+///Violation is reported at compile time, by any constructor:
+///
+///```compile_fail
+///let _ = stroka::SmallString::<300>::new();
+///```
+///
+///```compile_fail
+///let _ = stroka::SmallString::<256>::new_str("lolka");
+///```
+///
+///```
+///let stroka = stroka::SmallString::<255>::new_str("lolka");
+///assert_eq!(stroka.capacity(), 255);
+///```
+///
+///## Note
+///
+///This API is not part of `String` original API.
+pub enum SmallString<const N: usize> {
     #[doc(hidden)]
     Heap(HeapStr),
     #[doc(hidden)]
-    Sso(StrBuf),
+    Sso(StrBuf<N>),
 }
 
-impl String {
+const _: () = assert!(mem::size_of::<String>() == mem::size_of::<usize>() * 2);
+const _: () = assert!(String::INLINE_CAPACITY == StrBuf::<SSO_MAX_SIZE>::capacity());
+const _: () = assert!(mem::size_of::<Option<String>>() == mem::size_of::<usize>() * 2);
+const _: () = assert!(mem::size_of::<Option<Option<String>>>() == mem::size_of::<usize>() * 2);
+
+impl<const N: usize> SmallString<N> {
+    ///Number of bytes that can be stored inline, without heap allocation.
+    ///
+    ///For `String` it is `mem::size_of::<usize>() * 2 - 2`, i.e. `14` on 64bit targets and `6` on 32bit targets.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    //Evaluated by every constructor, hence invalid `N` fails to compile.
+    pub const INLINE_CAPACITY: usize = {
+        assert!(N <= u8::MAX as usize, "Inline capacity cannot be more than 255");
+        N
+    };
+
+    #[inline(always)]
+    ///Returns number of bytes that can be stored inline, without heap allocation.
+    ///
+    ///```
+    ///let stroka = stroka::String::new();
+    ///assert_eq!(stroka.capacity(), stroka::String::inline_capacity());
+    ///```
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub const fn inline_capacity() -> usize {
+        Self::INLINE_CAPACITY
+    }
+
+    #[inline(always)]
+    ///Returns whether `string` can be stored inline, without heap allocation.
+    ///
+    ///```
+    ///let key = "id";
+    ///assert!(stroka::String::fits_inline(key));
+    ///assert!(!stroka::String::new_str(key).is_alloc());
+    ///```
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub const fn fits_inline(string: &str) -> bool {
+        string.len() <= Self::INLINE_CAPACITY
+    }
+
     ///Creates new empty instance.
     #[inline]
     pub const fn new() -> Self {
-        Self::Sso(StrBuf::new())
+        let _ = Self::INLINE_CAPACITY;
+        Self::Sso(StrBuf::<N>::new())
+    }
+
+    #[inline(always)]
+    ///Swaps content of two strings, without copying or re-allocating.
+    ///
+    ///Heap storage travels together with its content, retaining capacity and pointer, while
+    ///inline content is copied as part of string itself, hence pointers into it are invalidated.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn swap(&mut self, other: &mut Self) {
+        mem::swap(self, other)
+    }
+
+    #[inline(always)]
+    ///Takes content of string, leaving empty inline string in its place.
+    ///
+    ///Heap storage is moved into returned string, retaining capacity and pointer.
+    ///Original string keeps no allocation and can be re-used.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn take(&mut self) -> Self {
+        mem::replace(self, Self::new())
     }
 
     ///Creates new string with provided initial value.
@@ -110,9 +421,14 @@ impl String {
     ///This API is not part of `String` original API.
     #[inline]
     pub fn new_str(text: &str) -> Self {
-        match StrBuf::from_str_checked(text) {
+        let _ = Self::INLINE_CAPACITY;
+        match StrBuf::<N>::from_str_checked(text) {
             Ok(sso) => Self::Sso(sso),
-            Err(_) => Self::Heap(text.into()),
+            Err(_) => {
+                let mut heap = heap_with_capacity::<N>(text.len());
+                heap.extend_from_slice(text.as_bytes());
+                Self::Heap(heap)
+            },
         }
     }
 
@@ -127,25 +443,51 @@ impl String {
     ///In case of buffer overflow.
     #[inline]
     pub const fn new_sso(text: &str) -> Self {
-        Self::Sso(StrBuf::from_str(text))
+        let _ = Self::INLINE_CAPACITY;
+        Self::Sso(StrBuf::<N>::from_str(text))
     }
 
     ///Creates new empty instance with specified capacity.
     ///
     ///If `capacity` is greater than static buffer can hold,
     ///`String` immediately allocates storage on heap.
+    ///
+    ///## Panics
+    ///
+    ///With `sso-only` feature, if `capacity` is greater than static buffer can hold.
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
-        if capacity <= StrBuf::capacity() {
+        let _ = Self::INLINE_CAPACITY;
+        if capacity <= StrBuf::<N>::capacity() {
             Self::new()
         } else {
-            Self::Heap(HeapStr::with_capacity(capacity))
+            Self::Heap(heap_with_capacity::<N>(capacity))
         }
     }
 
+    #[cfg(feature = "tuning")]
+    #[inline]
+    ///Returns statistics of inline strings moved onto heap.
+    ///
+    ///Statistics are process-global and shared by all inline capacities, see `growth::growth_stats`.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn growth_stats() -> growth::GrowthStats {
+        growth::growth_stats()
+    }
+
     #[inline]
     ///Returns whether string is heap allocated.
+    ///
+    ///Always `false` with `sso-only` feature.
     pub const fn is_alloc(&self) -> bool {
+        #[cfg(feature = "sso-only")]
+        {
+            false
+        }
+        #[cfg(not(feature = "sso-only"))]
         match self {
             Self::Heap(_) => true,
             Self::Sso(_) => false,
@@ -156,13 +498,70 @@ impl String {
     ///Sets string length, ignoring whether capacity is available.
     ///
     ///User is responsible to guarantee that `0..new_len` is valid string
+    ///
+    ///## Safety
+    ///
+    ///`new_len` must not exceed `capacity()`.
+    ///For inline (SSO) string it means `new_len` above inline capacity is undefined behaviour,
+    ///as length is stored in a single byte. Debug builds panic on such lengths.
     pub unsafe fn set_len(&mut self, new_len: usize) {
         match self {
             Self::Heap(ref mut buf) => buf.set_len(new_len ),
-            Self::Sso(ref mut string) => string.set_len(new_len as u8),
+            Self::Sso(ref mut string) => set_sso_len(string, new_len),
+        }
+    }
+
+    #[inline]
+    ///Returns the remaining spare capacity of the string as slice of uninitialized bytes.
+    ///
+    ///Slice covers bytes between `len()` and `capacity()` of currently active storage.
+    ///After writing valid UTF-8 into the beginning of the slice, caller must call `set_len`
+    ///in order to make written bytes part of the string.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn spare_capacity_mut(&mut self) -> &mut [mem::MaybeUninit<u8>] {
+        let len = self.len();
+        let spare = self.capacity() - len;
+        if spare == 0 {
+            return &mut [];
+        }
+
+        unsafe {
+            core::slice::from_raw_parts_mut(self.as_mut_ptr().add(len) as *mut mem::MaybeUninit<u8>, spare)
         }
     }
 
+    #[inline]
+    ///Creates string by letting `writer` initialize its storage directly.
+    ///
+    ///Storage of at least `capacity` bytes is allocated (or inline buffer is used if it fits), its
+    ///uninitialized bytes are passed to `writer`, which returns number of bytes written from the start
+    ///of the slice. Debug builds verify that written bytes are valid UTF-8.
+    ///
+    ///## Safety
+    ///
+    ///`writer` must initialize first `len` bytes of the slice with valid UTF-8, where `len` is returned value.
+    ///
+    ///## Panics
+    ///
+    ///If returned length exceeds length of the slice.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub unsafe fn from_raw_writer<F: FnOnce(&mut [mem::MaybeUninit<u8>]) -> usize>(capacity: usize, writer: F) -> Self {
+        let mut res = Self::with_capacity(capacity);
+        let spare = res.spare_capacity_mut();
+        let spare_len = spare.len();
+        let len = writer(spare);
+        assert!(len <= spare_len, "writer returned length {} exceeding buffer of {} bytes", len, spare_len);
+        res.set_len(len);
+        debug_assert!(core::str::from_utf8(res.as_bytes()).is_ok(), "writer produced invalid UTF-8");
+        res
+    }
+
     #[inline(always)]
     ///Returns length of the underlying bytes storage.
     pub fn len(&self) -> usize {
@@ -175,7 +574,8 @@ impl String {
     #[inline(always)]
     fn assert_heap_from_sso(&self, capacity: usize) -> HeapStr {
         if let Self::Sso(ref buf) = self {
-            let mut heap = HeapStr::with_capacity(capacity);
+            let mut heap = heap_with_capacity::<N>(promotion_capacity(capacity));
+            record_promotion(&heap);
 
             unsafe {
                 ptr::copy_nonoverlapping(buf.as_ptr() as *const _, heap.as_mut_ptr(), buf.len());
@@ -188,107 +588,465 @@ impl String {
         }
     }
 
+    #[cold]
+    #[inline(never)]
+    //Moves inline content onto heap with at least `capacity` bytes.
+    //Kept out of line as short strings are expected to stay inline.
+    fn promote_to_heap(&mut self, capacity: usize) -> &mut HeapStr {
+        *self = Self::Heap(self.assert_heap_from_sso(capacity));
+        match self {
+            Self::Heap(ref mut heap) => heap,
+            Self::Sso(_) => unreach!(),
+        }
+    }
+
+    ///Clones string, returning error instead of aborting on allocation failure.
+    ///
+    ///Inline strings are cloned infallibly, while heap storage is copied via fallible allocation.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn try_clone(&self) -> Result<Self, TryReserveError> {
+        match self {
+            Self::Sso(ref sso) => Ok(Self::Sso(*sso)),
+            Self::Heap(ref heap) => {
+                let mut result = try_heap_with_capacity(heap.len())?;
+                result.extend_from_slice(heap.as_slice());
+                Ok(Self::Heap(result))
+            }
+        }
+    }
+
     #[inline]
     ///Reserves additional space to store at least `additional` number of elements.
     ///
     ///The capacity may be increased by more than `additional` bytes if it chooses, to prevent
     ///frequent reallocations.
+    ///
+    ///Heap capacity is at least doubled and then rounded up to multiple of 8 bytes (16 bytes beyond
+    ///128 bytes), matching allocator's size classes. The same policy applies whenever string grows
+    ///implicitly, while `reserve_exact` allocates exactly what is requested.
+    ///
+    ///## Panics
+    ///
+    ///Panics if the new capacity overflows `usize`.
     pub fn reserve(&mut self, additional: usize) {
         let capacity = self.capacity();
-        let required = self.len() + additional;
+        let required = required_capacity(self.len(), additional);
 
         if required <= capacity {
             return;
         }
 
         match self {
-            Self::Sso(_) => *self = Self::Heap(self.assert_heap_from_sso(required)),
-            Self::Heap(ref mut string) => string.reserve(additional),
+            Self::Sso(_) => {
+                self.promote_to_heap(round_capacity(required));
+            },
+            Self::Heap(ref mut string) => grow_heap(string, additional),
         }
     }
 
     ///Reserves additional space to store exactly `additional` number of elements.
+    ///
+    ///## Panics
+    ///
+    ///Panics if the new capacity overflows `usize`.
     pub fn reserve_exact(&mut self, additional: usize) {
         let capacity = self.capacity();
-        let required = self.len() + additional;
+        let required = required_capacity(self.len(), additional);
 
         if required <= capacity {
             return;
         }
 
         match self {
-            Self::Sso(_) => *self = Self::Heap(self.assert_heap_from_sso(required)),
+            Self::Sso(_) => {
+                self.promote_to_heap(required);
+            },
             Self::Heap(ref mut string) => string.reserve_exact(additional),
         }
     }
 
-    #[inline]
-    ///Shrinks the capacity of this `String` to match its length.
+    ///Tries to reserve additional space to store at least `additional` number of elements.
     ///
-    ///Does nothing while string is not heap allocated.
-    pub fn shrink_to_fit(&mut self) {
-        if let Self::Heap(ref mut heap) = self {
-            heap.shrink_to_fit();
+    ///Unlike `reserve`, returns error instead of panicking or aborting on allocation failure.
+    ///Does nothing if string already has enough capacity, otherwise follows the same growth policy as `reserve`.
+    ///
+    ///With `sso-only` feature, returns `CapacityOverflow` if content would not fit static buffer.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required = match self.len().checked_add(additional) {
+            Some(required) => required,
+            None => return Err(TryReserveError::CapacityOverflow),
+        };
+
+        if required <= self.capacity() {
+            return Ok(());
         }
-    }
 
-    #[inline]
-    ///Returns `capacity`, indicating number of elements, that can be stored by underlying storage.
-    pub fn capacity(&self) -> usize {
         match self {
-            Self::Heap(ref heap) => heap.capacity(),
-            Self::Sso(_) => StrBuf::capacity(),
+            Self::Sso(_) => self.try_promote_to_heap(round_capacity(required)),
+            Self::Heap(ref mut heap) => {
+                let len = heap.len();
+                let capacity = growth_capacity(heap.capacity(), required);
+                heap.try_reserve_exact(capacity - len).map_err(Into::into)
+            },
         }
     }
 
-    #[inline(always)]
-    ///Returns pointer to the underlying storage.
-    pub fn as_ptr(&self) -> *const u8 {
+    ///Tries to reserve additional space to store exactly `additional` number of elements.
+    ///
+    ///Unlike `reserve_exact`, returns error instead of panicking or aborting on allocation failure.
+    ///Does nothing if string already has enough capacity.
+    ///
+    ///With `sso-only` feature, returns `CapacityOverflow` if content would not fit static buffer.
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required = match self.len().checked_add(additional) {
+            Some(required) => required,
+            None => return Err(TryReserveError::CapacityOverflow),
+        };
+
+        if required <= self.capacity() {
+            return Ok(());
+        }
+
         match self {
-            Self::Heap(ref heap) => heap.as_ptr(),
-            Self::Sso(ref sso) => sso.as_ptr(),
+            Self::Sso(_) => self.try_promote_to_heap(required),
+            Self::Heap(ref mut heap) => heap.try_reserve_exact(additional).map_err(Into::into),
         }
     }
 
+    #[cold]
+    #[inline(never)]
+    //Fallible counterpart of `promote_to_heap`
+    fn try_promote_to_heap(&mut self, capacity: usize) -> Result<(), TryReserveError> {
+        let mut heap = try_heap_with_capacity(promotion_capacity(capacity))?;
+        record_promotion(&heap);
+        heap.extend_from_slice(self.as_bytes());
+        *self = Self::Heap(heap);
+        Ok(())
+    }
+
     #[inline]
-    ///Returns mutable pointer to the underlying storage.
+    ///Shrinks the capacity of this `String` to match its length.
     ///
-    ///Note that write to such pointer is unsafe not only because of
-    ///potential overflow/invalid address, but due the fact that user must write valid utf-8 byte sequence.
-    pub fn as_mut_ptr(&mut self) -> *mut u8 {
-        match self {
-            Self::Heap(ref mut heap) => heap.as_mut_ptr(),
-            Self::Sso(ref mut sso) => sso.as_mut_ptr(),
-        }
+    ///Heap allocated content that fits static buffer is moved back inline, freeing allocation.
+    ///
+    ///Does nothing while string is not heap allocated.
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_to(0)
     }
 
-    #[inline(always)]
-    ///Access content of string as bytes.
-    pub fn as_bytes(&self) -> &[u8] {
-        match self {
-            Self::Heap(ref heap) => heap.as_slice(),
-            Self::Sso(ref sso) => sso.as_slice(),
+    #[inline]
+    ///Shrinks the capacity of this `String` with a lower bound.
+    ///
+    ///The capacity will remain at least as large as both the length and the supplied value.
+    ///
+    ///Heap allocated content is moved back inline when both length and `min_capacity` fit static buffer.
+    ///
+    ///Does nothing while string is not heap allocated or its capacity is already lower.
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        if let Self::Heap(ref mut heap) = self {
+            let min_capacity = core::cmp::max(heap.len(), min_capacity);
+            if min_capacity <= StrBuf::<N>::capacity() {
+                self.try_inline();
+            } else if heap.capacity() > min_capacity {
+                heap.shrink_to(min_capacity);
+            }
         }
     }
 
-    #[inline(always)]
-    ///Access content of string as mutable bytes.
+    ///Attempts to move heap allocated content back inline, freeing allocation.
     ///
-    ///Note that modifying this slice is `unsafe` hence this function is marked unsafe.
-    pub unsafe fn as_mut_bytes(&mut self) -> &mut [u8] {
+    ///Returns whether string is inline afterwards, which is always the case for already inline string.
+    ///Does nothing if length exceeds static buffer capacity.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn try_inline(&mut self) -> bool {
         match self {
-            Self::Heap(ref mut heap) => heap.as_mut_slice(),
-            Self::Sso(ref mut sso) => sso.as_mut_slice(),
+            Self::Heap(ref heap) => match heap.len() <= StrBuf::<N>::capacity() {
+                true => {
+                    let mut sso = StrBuf::<N>::new();
+                    unsafe {
+                        ptr::copy_nonoverlapping(heap.as_ptr(), sso.as_mut_ptr(), heap.len());
+                        set_sso_len(&mut sso, heap.len());
+                    }
+                    *self = Self::Sso(sso);
+                    true
+                },
+                false => false,
+            },
+            Self::Sso(_) => true,
         }
     }
 
+    #[inline]
+    ///Converts string into vector of bytes.
+    ///
+    ///Content is copied into newly allocated vector with capacity equal to length.
+    pub fn into_bytes(self) -> alloc::vec::Vec<u8> {
+        self.as_bytes().to_vec()
+    }
 
     #[inline(always)]
-    ///Gets string slice.
-    pub fn as_str(&self) -> &str {
-        unsafe {
-            core::str::from_utf8_unchecked(self.as_bytes())
-        }
+    ///Returns iterator over byte offsets of every character boundary, from `0` to `len()` inclusive.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn char_boundaries(&self) -> CharBoundaries<'_> {
+        CharBoundaries::new(self.as_str())
+    }
+
+    #[inline]
+    ///Converts string into owned iterator over its characters.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn into_chars(self) -> IntoChars<N> {
+        IntoChars {
+            start: 0,
+            end: self.len(),
+            string: self,
+        }
+    }
+
+    #[inline]
+    ///Converts string into iterator over its lines as owned strings.
+    ///
+    ///Lines are split by either `\n` or `\r\n`, with line terminators stripped.
+    ///Final line ending is optional and, same as for `str::lines`, does not produce empty line.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn into_lines(self) -> IntoLines<N> {
+        IntoLines {
+            string: self,
+            cursor: 0,
+        }
+    }
+
+    #[inline]
+    ///Consumes and leaks the string, returning a mutable reference to its content.
+    ///
+    ///Heap storage is leaked as it is, while inline content is moved onto heap first, as static
+    ///buffer cannot outlive string.
+    ///
+    ///## Panics
+    ///
+    ///With `sso-only` feature, if string is not heap allocated.
+    pub fn leak(self) -> &'static mut str {
+        let heap = match self {
+            Self::Heap(heap) => heap,
+            Self::Sso(_) => self.assert_heap_from_sso(self.len()),
+        };
+
+        unsafe {
+            core::str::from_utf8_unchecked_mut(HeapStr::leak(heap))
+        }
+    }
+
+    ///Decomposes string into its raw parts: `(pointer, length, capacity, is_heap)`.
+    ///
+    ///Heap storage is given up as it is, while inline content is moved onto heap first, as static
+    ///buffer cannot outlive string. `is_heap` indicates whether string was already heap allocated,
+    ///i.e. whether content was not copied.
+    ///
+    ///Returned parts can be turned back into `String` using `from_heap_parts` only.
+    ///
+    ///## Panics
+    ///
+    ///With `sso-only` feature, if string is not heap allocated.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn into_raw_parts(self) -> (*mut u8, usize, usize, bool) {
+        let (heap, is_heap) = match self {
+            Self::Heap(heap) => (heap, true),
+            Self::Sso(_) => (self.assert_heap_from_sso(self.len()), false),
+        };
+
+        let (ptr, len, capacity) = heap.into_raw_parts();
+        (ptr, len, capacity, is_heap)
+    }
+
+    #[inline]
+    ///Creates heap allocated string from raw parts, previously returned by `into_raw_parts`.
+    ///
+    ///## Safety
+    ///
+    ///- `ptr`, `len` and `capacity` must be exactly the parts returned by `into_raw_parts`,
+    ///as heap storage keeps its length and capacity within allocation itself. Use `set_len`
+    ///on resulting string in order to change length.
+    ///- Bytes `0..len` must be valid UTF-8.
+    ///- Parts must be used only once, as resulting string owns allocation.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub unsafe fn from_heap_parts(ptr: *mut u8, len: usize, capacity: usize) -> Self {
+        let _ = Self::INLINE_CAPACITY;
+        Self::Heap(HeapStr::from_raw_parts(ptr, len, capacity))
+    }
+
+    #[inline]
+    ///Converts string into boxed `str`.
+    ///
+    ///Content is copied into newly allocated box of exactly `len()` bytes.
+    pub fn into_boxed_str(self) -> alloc::boxed::Box<str> {
+        alloc::boxed::Box::from(self.as_str())
+    }
+
+    #[inline]
+    ///Converts string into `alloc::string::String`.
+    ///
+    ///Content is copied into single allocation of exactly `len()` bytes.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn into_std(self) -> alloc::string::String {
+        alloc::string::String::from(self.as_str())
+    }
+
+    #[inline(always)]
+    ///Creates string from `alloc::string::String`.
+    ///
+    ///Content is always copied, as allocation cannot be re-used, but it is stored inline whenever it fits.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn from_std(string: alloc::string::String) -> Self {
+        Self::new_str(&string)
+    }
+
+    #[inline]
+    ///Converts string into its most compact representation.
+    ///
+    ///Content that fits static buffer is moved inline, otherwise heap storage is shrunk to match
+    ///its length. Already compact string is returned as it is.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn into_compact(self) -> Self {
+        match self {
+            Self::Heap(mut heap) => match StrBuf::<N>::from_str_checked(heap.as_str()) {
+                Ok(sso) => Self::Sso(sso),
+                Err(_) => {
+                    heap.shrink_to_fit();
+                    Self::Heap(heap)
+                }
+            },
+            Self::Sso(sso) => Self::Sso(sso),
+        }
+    }
+
+    #[inline]
+    ///Returns `capacity`, indicating number of elements, that can be stored by underlying storage.
+    pub fn capacity(&self) -> usize {
+        match self {
+            Self::Heap(ref heap) => heap.capacity(),
+            Self::Sso(_) => StrBuf::<N>::capacity(),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns pointer to the underlying storage.
+    pub fn as_ptr(&self) -> *const u8 {
+        match self {
+            Self::Heap(ref heap) => heap.as_ptr(),
+            Self::Sso(ref sso) => sso.as_ptr(),
+        }
+    }
+
+    #[inline]
+    ///Returns mutable pointer to the underlying storage.
+    ///
+    ///Note that write to such pointer is unsafe not only because of
+    ///potential overflow/invalid address, but due the fact that user must write valid utf-8 byte sequence.
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        match self {
+            Self::Heap(ref mut heap) => heap.as_mut_ptr(),
+            Self::Sso(ref mut sso) => sso.as_mut_ptr(),
+        }
+    }
+
+    #[inline(always)]
+    ///Access content of string as bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Heap(ref heap) => heap.as_slice(),
+            Self::Sso(ref sso) => sso.as_slice(),
+        }
+    }
+
+    #[inline(always)]
+    ///Access content of string as mutable bytes.
+    ///
+    ///Note that modifying this slice is `unsafe` hence this function is marked unsafe.
+    pub unsafe fn as_mut_bytes(&mut self) -> &mut [u8] {
+        match self {
+            Self::Heap(ref mut heap) => heap.as_mut_slice(),
+            Self::Sso(ref mut sso) => sso.as_mut_slice(),
+        }
+    }
+
+    #[inline]
+    ///Access content of string as mutable bytes, if string consists only of ASCII characters.
+    ///
+    ///Returned guard masks every byte to ASCII range on drop (i.e. high bit is cleared), hence
+    ///writing non-ASCII byte cannot break UTF-8 invariant.
+    ///String appears empty until guard is dropped, so leaked guard leaves it empty.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn as_ascii_bytes_mut(&mut self) -> Option<AsciiBytesMut<'_, N>> {
+        if self.as_str().is_ascii() {
+            Some(AsciiBytesMut::new(self))
+        } else {
+            None
+        }
+    }
+
+    ///Capitalizes every word in place, using ASCII rules.
+    ///
+    ///Words are separated by ASCII whitespace. First ASCII letter of each word is converted to
+    ///upper case, while the rest of ASCII letters are converted to lower case.
+    ///Non-ASCII characters are left untouched, hence length never changes.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn make_ascii_titlecase(&mut self) {
+        let mut word_start = true;
+        //Only ASCII bytes are modified, which keeps UTF-8 valid.
+        for byte in unsafe { self.as_mut_bytes() } {
+            if byte.is_ascii_whitespace() {
+                word_start = true;
+            } else if byte.is_ascii_alphabetic() {
+                match word_start {
+                    true => byte.make_ascii_uppercase(),
+                    false => byte.make_ascii_lowercase(),
+                }
+                word_start = false;
+            }
+        }
+    }
+
+    #[inline(always)]
+    ///Gets string slice.
+    pub fn as_str(&self) -> &str {
+        unsafe {
+            core::str::from_utf8_unchecked(self.as_bytes())
+        }
     }
 
     #[inline(always)]
@@ -299,6 +1057,81 @@ impl String {
         }
     }
 
+    #[inline(always)]
+    ///Returns whether content of string is byte-wise equal to `bytes`.
+    ///
+    ///Bytes that are not valid UTF-8 are simply not equal.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn eq_bytes(&self, bytes: &[u8]) -> bool {
+        self.as_bytes() == bytes
+    }
+
+    #[inline]
+    ///Returns whether string consists of exactly one character `ch`.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn eq_char(&self, ch: char) -> bool {
+        let mut buf = [0u8; 4];
+        self.as_str() == ch.encode_utf8(&mut buf)
+    }
+
+    #[inline(always)]
+    ///Returns bytes of string as key, which ordering is the same as ordering of strings.
+    ///
+    ///Comparing these slices lexicographically is guaranteed to be equivalent to `Ord` of strings,
+    ///as UTF-8 preserves ordering of code points. Hence it is suitable for byte-keyed structures
+    ///(e.g. radix trie) that need to agree with ordering of `String`.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn as_ord_key(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    #[inline(always)]
+    ///Compares content of string with `bytes` lexicographically.
+    ///
+    ///For valid UTF-8 `bytes` result is the same as comparing strings.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn cmp_bytes(&self, bytes: &[u8]) -> core::cmp::Ordering {
+        self.as_bytes().cmp(bytes)
+    }
+
+    #[inline(always)]
+    ///Feeds content of string into `hasher`, producing the same hash as `str`.
+    ///
+    ///This is what `Hash` implementation does, intended for custom keys wrapping `String`.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn hash_str<H: core::hash::Hasher>(&self, hasher: &mut H) {
+        core::hash::Hash::hash(self.as_str(), hasher)
+    }
+
+    #[inline(always)]
+    ///Feeds exactly raw bytes of string into `hasher`, without any length prefix or suffix.
+    ///
+    ///Unlike `Hash` implementation, it does not depend on how `str` is hashed by `core`, hence
+    ///result is the same as calling `hasher.write(self.as_bytes())`.
+    ///Note that such hashing is not prefix-free, i.e. hashing `"ab"` and `"c"` in sequence is the same as `"a"` and `"bc"`.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn write_hash_into<H: core::hash::Hasher>(&self, hasher: &mut H) {
+        hasher.write(self.as_bytes())
+    }
+
     #[inline]
     ///Clears content of string, leaving allocated storage intact.
     pub fn clear(&mut self) {
@@ -323,30 +1156,236 @@ impl String {
     pub fn truncate(&mut self, new_len: usize) {
         match self {
             Self::Heap(ref mut heap) => {
-                if new_len > heap.len() {
+                if new_len >= heap.len() {
                     return;
                 }
 
-                assert!(heap.as_str().is_char_boundary(new_len));
+                assert_char_boundary(heap.as_str(), new_len);
                 //in case of index out of boundary we panic above
                 unsafe {
                     heap.set_len(new_len);
                 }
             },
             Self::Sso(ref mut sso) => {
-                if new_len > sso.len() {
+                if new_len >= sso.len() {
                     return;
                 }
 
-                assert!(sso.is_char_boundary(new_len));
+                assert_char_boundary(sso.as_str(), new_len);
                 //in case of index out of boundary we panic above
                 unsafe {
-                    sso.set_len(new_len as u8);
+                    set_sso_len(sso, new_len);
                 }
             },
         }
     }
 
+    ///Shortens `String` to contain only its last `keep_last` characters.
+    ///
+    ///If `keep_last` is greater or equal to number of characters, this has no effect.
+    ///
+    ///Note that this method has no effect on the allocated capacity of the string.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn truncate_front_chars(&mut self, keep_last: usize) {
+        if keep_last == 0 {
+            self.clear();
+            return;
+        }
+
+        if let Some((start, _)) = self.as_str().char_indices().rev().nth(keep_last - 1) {
+            self.remove_range(..start);
+        }
+    }
+
+    #[inline]
+    ///Splits string into two slices after first `n` characters.
+    ///
+    ///Returns `None` if `n` exceeds number of characters.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn checked_split_at_char(&self, n: usize) -> Option<(&str, &str)> {
+        let text = self.as_str();
+        let idx = match n {
+            0 => 0,
+            n => match text.char_indices().nth(n - 1) {
+                Some((idx, ch)) => idx + ch.len_utf8(),
+                None => return None,
+            },
+        };
+        Some(text.split_at(idx))
+    }
+
+    #[inline]
+    ///Splits string into two slices after first `n` characters.
+    ///
+    ///## Panics
+    ///
+    ///If `n` exceeds number of characters.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn split_at_char(&self, n: usize) -> (&str, &str) {
+        match self.checked_split_at_char(n) {
+            Some(res) => res,
+            None => panic!("char index {} is out of bounds", n),
+        }
+    }
+
+    ///Splits string into two owned strings after first `n` characters.
+    ///
+    ///Storage of the string is re-used for the first half, hence at most one allocation is performed for
+    ///the second half. Halves that fit static buffer are stored inline.
+    ///
+    ///## Panics
+    ///
+    ///If `n` exceeds number of characters.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn split_at_char_owned(mut self, n: usize) -> (Self, Self) {
+        let (head, tail) = self.split_at_char(n);
+        let idx = head.len();
+        let tail = Self::new_str(tail);
+        self.truncate(idx);
+        self.try_inline();
+        (self, tail)
+    }
+
+    ///Resizes `String` so that it contains exactly `new_len` characters.
+    ///
+    ///If `new_len` is less than number of characters, string is truncated at corresponding char
+    ///boundary. Otherwise it is extended with copies of `ch`, moving onto heap if necessary.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn resize(&mut self, new_len: usize, ch: char) {
+        if let Some(idx) = self.as_str().char_indices().map(|(idx, _)| idx).nth(new_len) {
+            self.truncate(idx);
+            return;
+        }
+
+        let additional = new_len - self.as_str().chars().count();
+        if additional == 0 {
+            return;
+        }
+
+        let mut buf = [0u8; 4];
+        let ch = ch.encode_utf8(&mut buf);
+        match ch.len().checked_mul(additional) {
+            Some(required) => self.reserve(required),
+            None => panic!("resize capacity overflow"),
+        }
+
+        for _ in 0..additional {
+            self.push_str(ch);
+        }
+    }
+
+    ///Resizes `String` so that its length in bytes is exactly `new_len`.
+    ///
+    ///If `new_len` is less than current length, string is truncated.
+    ///Otherwise it is extended with copies of `ch`, moving onto heap if necessary.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    ///
+    ///## Panics
+    ///
+    ///Panics if `ch` is not ASCII or if `new_len` does not lie on a `char` boundary.
+    pub fn resize_bytes(&mut self, new_len: usize, ch: u8) {
+        assert!(ch.is_ascii(), "fill byte must be ASCII");
+
+        let len = self.len();
+        if new_len <= len {
+            self.truncate(new_len);
+            return;
+        }
+
+        let additional = new_len - len;
+        self.reserve(additional);
+        unsafe {
+            ptr::write_bytes(self.as_mut_ptr().add(len), ch, additional);
+            self.set_len(new_len);
+        }
+    }
+
+    ///Pads string with `'0'` on the left until it contains `width` characters.
+    ///
+    ///Zeros are inserted after leading `'+'` or `'-'`, if any. Content is not required to be numeric.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn zfill_in_place(&mut self, width: usize) {
+        let pad = match width.checked_sub(self.as_str().chars().count()) {
+            Some(0) | None => return,
+            Some(pad) => pad,
+        };
+        let sign_len = match self.as_bytes().first() {
+            Some(b'+') | Some(b'-') => 1,
+            _ => 0,
+        };
+
+        let len = self.len();
+        self.reserve(pad);
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            ptr::copy(ptr.add(sign_len), ptr.add(sign_len + pad), len - sign_len);
+            ptr::write_bytes(ptr.add(sign_len), b'0', pad);
+            self.set_len(len + pad);
+        }
+    }
+
+    ///Repeats content of the string `times`, without creating temporary `String`.
+    ///
+    ///`times == 0` clears string, while `times == 1` does nothing.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    ///
+    ///## Panics
+    ///
+    ///This function will panic if the capacity would overflow.
+    pub fn repeat_in_place(&mut self, times: usize) {
+        match times {
+            0 => self.clear(),
+            1 => (),
+            _ => {
+                let len = self.len();
+                if len == 0 {
+                    return;
+                }
+
+                let required = match len.checked_mul(times) {
+                    Some(required) => required,
+                    None => panic!("repeat capacity overflow"),
+                };
+
+                self.reserve(required - len);
+                let ptr = self.as_mut_ptr();
+                let mut filled = len;
+                unsafe {
+                    while filled <= required - filled {
+                        ptr::copy_nonoverlapping(ptr, ptr.add(filled), filled);
+                        filled *= 2;
+                    }
+                    ptr::copy_nonoverlapping(ptr, ptr.add(filled), required - filled);
+                    self.set_len(required);
+                }
+            }
+        }
+    }
+
     #[inline(always)]
     ///Returns whether string is empty or not.
     pub fn is_empty(&self) -> bool {
@@ -358,7 +1397,7 @@ impl String {
     pub fn pop(&mut self) -> Option<char> {
         match self {
             Self::Heap(ref mut heap) => {
-                let result = heap.as_str().chars().last()?;
+                let result = heap.as_str().chars().next_back()?;
                 unsafe {
                     heap.set_len(heap.len() - result.len_utf8());
                 }
@@ -366,16 +1405,15 @@ impl String {
                 Some(result)
             },
             Self::Sso(ref mut sso) => {
-                let result = sso.as_str().chars().last()?;
+                let result = sso.as_str().chars().next_back()?;
                 unsafe {
-                    sso.set_len(sso.len() as u8 - result.len_utf8() as u8);
+                    set_sso_len(sso, sso.len() - result.len_utf8());
                 }
                 Some(result)
             }
         }
     }
 
-    #[inline]
     ///Removes character at the specified `idx`
     ///
     ///This is an *O*(*n*) operation, as it requires copying every element in the buffer.
@@ -386,9 +1424,11 @@ impl String {
     pub fn remove(&mut self, idx: usize) -> char {
         match self {
             Self::Heap(ref mut heap) => {
-                let ch = match heap.as_str()[idx..].chars().next() {
+                assert_char_boundary(heap.as_str(), idx);
+                //Boundary is checked above, hence slicing cannot fail.
+                let ch = match unsafe { heap.as_str().get_unchecked(idx..) }.chars().next() {
                     Some(ch) => ch,
-                    None => panic!("cannot remove a char from the end of a string")
+                    None => boundary_fail(idx, heap.len()),
                 };
 
                 let next = idx + ch.len_utf8();
@@ -401,22 +1441,106 @@ impl String {
                 ch
             },
             Self::Sso(ref mut sso) => {
-                let ch = match sso.as_str()[idx..].chars().next() {
+                assert_char_boundary(sso.as_str(), idx);
+                //Boundary is checked above, hence slicing cannot fail.
+                let ch = match unsafe { sso.as_str().get_unchecked(idx..) }.chars().next() {
                     Some(ch) => ch,
-                    None => panic!("cannot remove a char from the end of a string")
+                    None => boundary_fail(idx, sso.len()),
                 };
 
                 let next = idx + ch.len_utf8();
                 let len = sso.len();
                 unsafe {
                     ptr::copy(sso.as_ptr().add(next), sso.as_mut_ptr().add(idx), len - next);
-                    sso.set_len(len as u8 - (next as u8 - idx as u8));
+                    set_sso_len(sso, len - (next - idx));
                 }
                 ch
             }
         }
     }
 
+    ///Removes character at the specified `idx`, returning error if `idx` is invalid.
+    ///
+    ///This is an *O*(*n*) operation, as it requires copying every element in the buffer.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn try_remove(&mut self, idx: usize) -> Result<char, BoundaryError> {
+        if idx == self.len() {
+            return Err(BoundaryError::OutOfBounds {
+                index: idx,
+                len: self.len(),
+            });
+        }
+        check_char_boundary(self.as_str(), idx)?;
+        Ok(self.remove(idx))
+    }
+
+    ///Returns byte ranges of all non-overlapping occurrences of `pat`, searched from the start.
+    ///
+    ///Unlike `match_indices`, ranges do not borrow string, allowing to modify it afterwards.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn match_ranges(&self, pat: &str) -> alloc::vec::Vec<core::ops::Range<usize>> {
+        self.as_str().match_indices(pat).map(|(idx, matched)| idx..idx + matched.len()).collect()
+    }
+
+    ///Returns byte ranges of all occurrences of `pat` character.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn match_ranges_char(&self, pat: char) -> alloc::vec::Vec<core::ops::Range<usize>> {
+        let pat_len = pat.len_utf8();
+        self.as_str().match_indices(pat).map(|(idx, _)| idx..idx + pat_len).collect()
+    }
+
+    #[inline]
+    ///Returns number of non-overlapping occurrences of `pat`, searched from the start.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn count_matches(&self, pat: &str) -> usize {
+        self.as_str().matches(pat).count()
+    }
+
+    ///Removes all non-overlapping occurrences of `pat` in place.
+    ///
+    ///Matches are searched from the start, removing them in a single pass without affecting capacity.
+    ///Empty `pat` has no effect.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn remove_matches(&mut self, pat: &str) {
+        if pat.is_empty() {
+            return;
+        }
+
+        let len = self.len();
+        let ptr = self.as_mut_ptr();
+        let mut read = 0;
+        let mut write = 0;
+
+        //Content from `read` is never overwritten, as `write` cannot overtake it
+        while let Some(idx) = unsafe { core::str::from_utf8_unchecked(core::slice::from_raw_parts(ptr.add(read), len - read)) }.find(pat) {
+            unsafe {
+                ptr::copy(ptr.add(read), ptr.add(write), idx);
+            }
+            write += idx;
+            read += idx + pat.len();
+        }
+
+        unsafe {
+            ptr::copy(ptr.add(read), ptr.add(write), len - read);
+            self.set_len(write + len - read);
+        }
+    }
+
     ///Retains only the characters specified by the predicate.
     ///
     ///In other words, remove all characters `c` such that `cb(c)` returns `false`.
@@ -429,15 +1553,15 @@ impl String {
         }
 
         macro_rules! impl_retain {
-            ($storage:expr, $typ:ident) => {
-                struct LenSetter<'a> {
+            ($storage:expr, $typ:ty, [$($decl:tt)*], [$($arg:tt)*]) => {
+                struct LenSetter<'a, $($decl)*> {
                     storage: &'a mut $typ,
                     idx: usize,
                     del_bytes: usize,
                 }
 
                 //It is highly unlikely to be needed, but just in case
-                impl<'a> Drop for LenSetter<'a> {
+                impl<'a, $($decl)*> Drop for LenSetter<'a, $($arg)*> {
                     #[inline(always)]
                     fn drop(&mut self) {
                         let new_len = self.idx - self.del_bytes;
@@ -476,14 +1600,86 @@ impl String {
 
         match self {
             Self::Heap(ref mut heap) => {
-                impl_retain!(heap, HeapStr);
+                impl_retain!(heap, HeapStr, [], []);
             },
             Self::Sso(ref mut sso) => {
-                impl_retain!(sso, StrBuf);
+                impl_retain!(sso, StrBuf<M>, [const M: usize], [M]);
             }
         }
     }
 
+    ///Collapses each run of `ch` into single occurrence in place, returning number of removed characters.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn squeeze(&mut self, ch: char) -> usize {
+        let mut removed = 0;
+        let mut is_prev_match = false;
+        self.retain(|current| {
+            let is_match = current == ch;
+            let keep = !(is_match && is_prev_match);
+            is_prev_match = is_match;
+            removed += !keep as usize;
+            keep
+        });
+        removed
+    }
+
+    ///Collapses each run of repeated character into single occurrence in place, returning number of removed characters.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn squeeze_all(&mut self) -> usize {
+        let mut removed = 0;
+        let mut prev = None;
+        self.retain(|current| {
+            let keep = prev != Some(current);
+            prev = Some(current);
+            removed += !keep as usize;
+            keep
+        });
+        removed
+    }
+
+    #[inline(always)]
+    ///Appends given `string` at the end, returning `self` to allow chaining.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn append(&mut self, string: &str) -> &mut Self {
+        self.push_str(string);
+        self
+    }
+
+    #[inline]
+    ///Appends `value` formatted via `Display` at the end, returning `self` to allow chaining.
+    ///
+    ///Error returned by `value` formatting is ignored, leaving whatever was written before it.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn push_display<T: core::fmt::Display + ?Sized>(&mut self, value: &T) -> &mut Self {
+        let _ = core::fmt::Write::write_fmt(self, format_args!("{}", value));
+        self
+    }
+
+    #[inline]
+    ///Appends `value` formatted via `Debug` at the end, returning `self` to allow chaining.
+    ///
+    ///Error returned by `value` formatting is ignored, leaving whatever was written before it.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn push_debug<T: core::fmt::Debug + ?Sized>(&mut self, value: &T) -> &mut Self {
+        let _ = core::fmt::Write::write_fmt(self, format_args!("{:?}", value));
+        self
+    }
+
     #[inline(always)]
     ///Appends given `ch` at the end of the string.
     pub fn push(&mut self, ch: char) {
@@ -493,25 +1689,129 @@ impl String {
         self.push_str(res)
     }
 
+    #[inline]
+    ///Appends ASCII `byte` at the end.
+    ///
+    ///In case of non-ASCII byte, returns `AsciiError` with index `0`.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn push_ascii(&mut self, byte: u8) -> Result<(), AsciiError> {
+        self.push_ascii_str(&[byte])
+    }
+
+    ///Appends slice of ASCII bytes at the end.
+    ///
+    ///In case of non-ASCII byte, returns `AsciiError` with its index, leaving string unchanged.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn push_ascii_str(&mut self, bytes: &[u8]) -> Result<(), AsciiError> {
+        if let Some(index) = bytes.iter().position(|byte| !byte.is_ascii()) {
+            return Err(AsciiError {
+                index,
+                byte: bytes[index],
+            });
+        }
+
+        self.push_str(unsafe {
+            core::str::from_utf8_unchecked(bytes)
+        });
+        Ok(())
+    }
+
+    ///Appends bytes at the end, replacing invalid sequences with [REPLACEMENT_CHARACTER](https://doc.rust-lang.org/core/char/constant.REPLACEMENT_CHARACTER.html)
+    ///
+    ///Capacity for `bytes.len()` is reserved upfront, with valid parts copied as they are.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn push_utf8_lossy(&mut self, bytes: &[u8]) {
+        self.reserve(bytes.len());
+        for chunk in utf8_chunks(bytes) {
+            self.push_str(chunk.valid());
+            if !chunk.invalid().is_empty() {
+                self.push(core::char::REPLACEMENT_CHARACTER);
+            }
+        }
+    }
+
     #[inline]
     ///Appends given `string` at the end.
     pub fn push_str(&mut self, string: &str) {
+        let string_len = string.len();
         match self {
-            Self::Heap(ref mut heap) => heap.extend_from_slice(string.as_bytes()),
             Self::Sso(ref mut sso) => {
-                let len = sso.len();
-                let string_len = string.len();
-                if sso.remaining() < string_len {
-                    let mut heap = self.assert_heap_from_sso(len + string_len);
-                    heap.extend_from_slice(string.as_bytes());
-                    *self = Self::Heap(heap);
-                } else {
+                if sso.remaining() >= string_len {
                     unsafe {
                         sso.push_str_unchecked(string);
                     }
+                } else {
+                    let len = sso.len();
+                    self.promote_to_heap(round_capacity(len + string_len)).extend_from_slice(string.as_bytes());
                 }
-            }
+            },
+            Self::Heap(ref mut heap) => {
+                if heap.capacity() - heap.len() < string_len {
+                    grow_heap(heap, string_len);
+                }
+                heap.extend_from_slice(string.as_bytes());
+            },
+        }
+    }
+
+    #[inline(always)]
+    ///Appends given `ch` at the end of the string, only if it fits into current capacity.
+    ///
+    ///Never allocates. In case of insufficient capacity, returns `CapacityError`, leaving string untouched.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn try_push(&mut self, ch: char) -> Result<(), CapacityError> {
+        let mut buf = [0u8; 4];
+        self.try_push_str(ch.encode_utf8(&mut buf))
+    }
+
+    #[inline]
+    ///Appends given `string` at the end, only if it fits into current capacity.
+    ///
+    ///Never allocates. In case of insufficient capacity, returns `CapacityError`, leaving string untouched.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn try_push_str(&mut self, string: &str) -> Result<(), CapacityError> {
+        let remaining = self.capacity() - self.len();
+        if remaining < string.len() {
+            return Err(CapacityError {
+                missing: string.len() - remaining,
+            });
         }
+
+        self.push_str(string);
+        Ok(())
+    }
+
+    ///Appends the longest prefix of `string`, that fits into current capacity, returning the rest.
+    ///
+    ///Never allocates. Prefix ends at char boundary, hence returned suffix is always valid `str`.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn push_str_within_capacity<'s>(&mut self, string: &'s str) -> &'s str {
+        let mut idx = core::cmp::min(self.capacity() - self.len(), string.len());
+        while !string.is_char_boundary(idx) {
+            idx -= 1;
+        }
+
+        let (head, tail) = string.split_at(idx);
+        self.push_str(head);
+        tail
     }
 
     #[inline(always)]
@@ -525,7 +1825,6 @@ impl String {
         self.insert_str(idx, ch.encode_utf8(&mut bits))
     }
 
-    #[inline]
     ///Inserts `str` at the given position
     ///
     ///# Panics
@@ -534,36 +1833,99 @@ impl String {
     pub fn insert_str(&mut self, idx: usize, string: &str) {
         let string_len = string.len();
         match self {
+            Self::Sso(ref mut sso) => {
+                assert_char_boundary(sso.as_str(), idx);
+
+                let len = sso.len();
+                if sso.remaining() >= string_len {
+                    unsafe {
+                        insert_bytes_into(sso.as_mut_ptr(), len, idx, string.as_bytes());
+                        set_sso_len(sso, len + string_len);
+                    }
+                } else {
+                    let heap = self.promote_to_heap(round_capacity(len + string_len));
+                    unsafe {
+                        insert_bytes_into(heap.as_mut_ptr(), len, idx, string.as_bytes());
+                        heap.set_len(len + string_len);
+                    }
+                }
+            },
             Self::Heap(ref mut heap) => {
-                assert!(heap.as_str().is_char_boundary(idx));
+                assert_char_boundary(heap.as_str(), idx);
 
-                heap.reserve(string_len);
+                if heap.capacity() - heap.len() < string_len {
+                    grow_heap(heap, string_len);
+                }
                 unsafe {
                     insert_bytes_into(heap.as_mut_ptr(), heap.len(), idx, string.as_bytes());
                     heap.set_len(heap.len() + string_len);
                 }
             },
-            Self::Sso(ref mut sso) => {
-                assert!(sso.is_char_boundary(idx));
+        }
+    }
 
+    ///Inserts `str` at the start of string.
+    ///
+    ///When string has to be moved onto heap, both `string` and existing content are copied only once.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn prepend(&mut self, string: &str) {
+        let string_len = string.len();
+        match self {
+            Self::Sso(ref mut sso) => {
                 let len = sso.len();
-                if sso.remaining() < string_len {
-                    let mut heap = self.assert_heap_from_sso(len + string_len);
+                if sso.remaining() >= string_len {
                     unsafe {
-                        insert_bytes_into(heap.as_mut_ptr(), heap.len(), idx, string.as_bytes());
-                        heap.set_len(len + string_len);
+                        insert_bytes_into(sso.as_mut_ptr(), len, 0, string.as_bytes());
+                        set_sso_len(sso, len + string_len);
                     }
-                    *self = Self::Heap(heap);
                 } else {
+                    let mut heap = heap_with_capacity::<N>(promotion_capacity(round_capacity(len + string_len)));
+                    record_promotion(&heap);
                     unsafe {
-                        insert_bytes_into(sso.as_mut_ptr(), len, idx, string.as_bytes());
-                        sso.set_len(len as u8 + string_len as u8);
+                        ptr::copy_nonoverlapping(string.as_ptr(), heap.as_mut_ptr(), string_len);
+                        ptr::copy_nonoverlapping(sso.as_ptr(), heap.as_mut_ptr().add(string_len), len);
+                        heap.set_len(len + string_len);
                     }
+                    *self = Self::Heap(heap);
                 }
-            }
+            },
+            Self::Heap(ref mut heap) => {
+                if heap.capacity() - heap.len() < string_len {
+                    grow_heap(heap, string_len);
+                }
+                unsafe {
+                    insert_bytes_into(heap.as_mut_ptr(), heap.len(), 0, string.as_bytes());
+                    heap.set_len(heap.len() + string_len);
+                }
+            },
         }
     }
 
+    #[inline]
+    ///Inserts character at the start of string.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn prepend_char(&mut self, ch: char) {
+        let mut buf = [0u8; 4];
+        self.prepend(ch.encode_utf8(&mut buf));
+    }
+
+    ///Inserts `str` at the given position, returning error if `idx` is invalid.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn try_insert_str(&mut self, idx: usize, string: &str) -> Result<(), BoundaryError> {
+        check_char_boundary(self.as_str(), idx)?;
+        self.insert_str(idx, string);
+        Ok(())
+    }
+
     #[inline]
     ///Creates a draining iterator that removes the specified range in the `String` and yields the removed `chars`.
     ///
@@ -572,12 +1934,13 @@ impl String {
     ///# Panics
     ///
     ///Panics if the starting point or end point do not lie on a `char` boundary, or if they're out of bounds.
-    pub fn drain<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> Drain<'_> {
-        //Defense against retarded impl
-        let range_start = range.start_bound();
-        let range_end = range.end_bound();
+    pub fn drain<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, N> {
+        let (start, end) = range_bounds(&range);
+        self.drain_bounds(start, end)
+    }
 
-        let (start, end, _) = assert_range_len(self.as_str(), range_start, range_end);
+    fn drain_bounds(&mut self, start: Bound<usize>, end: Bound<usize>) -> Drain<'_, N> {
+        let (start, end, _) = assert_range_len(self.as_str(), start, end);
         let string = self as *mut _;
         let chars = unsafe {
             self.as_str().get_unchecked(start..end).chars()
@@ -591,6 +1954,7 @@ impl String {
         }
     }
 
+    #[inline]
     ///Removes the specified within the string.
     ///
     ///## Note
@@ -601,28 +1965,55 @@ impl String {
     ///
     ///Panics if the starting point or end point do not lie on a [`char`] boundary, or if they're out of bounds.
     pub fn remove_range<R: core::ops::RangeBounds<usize>>(&mut self, range: R) {
-        //Defense against retarded impl
-        let range_start = range.start_bound();
-        let range_end = range.end_bound();
+        let (start, end) = range_bounds(&range);
+        self.remove_range_bounds(start, end)
+    }
 
+    fn remove_range_bounds(&mut self, start: Bound<usize>, end: Bound<usize>) {
         match self {
             Self::Heap(ref mut heap) => {
-                let (start, end, range_size) = assert_range_len(heap.as_str(), range_start, range_end);
+                let (start, end, range_size) = assert_range_len(heap.as_str(), start, end);
                 unsafe {
                     ptr::copy(heap.as_ptr().add(end), heap.as_mut_ptr().add(start), heap.len() - start - range_size);
                     heap.set_len(heap.len() - range_size);
                 }
             },
             Self::Sso(ref mut sso) => {
-                let (start, end, range_size) = assert_range_len(sso.as_str(), range_start, range_end);
+                let (start, end, range_size) = assert_range_len(sso.as_str(), start, end);
                 unsafe {
                     ptr::copy(sso.as_ptr().add(end), sso.as_mut_ptr().add(start), sso.len() - start - range_size);
-                    sso.set_len(sso.len() as u8 - range_size as u8);
+                    set_sso_len(sso, sso.len() - range_size);
                 }
             }
         }
     }
 
+    #[inline]
+    ///Removes the specified range in the string, returning it as new `String`.
+    ///
+    ///Removed part is stored inline whenever it fits, regardless of the source storage.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    ///
+    ///## Panics
+    ///
+    ///Panics if the starting point or end point do not lie on a [`char`] boundary, or if they're out of bounds.
+    pub fn cut_range<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> Self {
+        let (start, end) = range_bounds(&range);
+        self.cut_range_bounds(start, end)
+    }
+
+    fn cut_range_bounds(&mut self, start: Bound<usize>, end: Bound<usize>) -> Self {
+        let (start, end, _) = assert_range_len(self.as_str(), start, end);
+        let result = Self::new_str(unsafe {
+            self.as_str().get_unchecked(start..end)
+        });
+        self.remove_range_bounds(Bound::Included(start), Bound::Excluded(end));
+        result
+    }
+
     #[inline]
     ///Removes the specified range in the string, and replaces it with the given string.
     ///The given string doesn't need to be the same length as the range.
@@ -631,93 +2022,410 @@ impl String {
     ///
     ///Panics if the starting point or end point do not lie on a [`char`] boundary, or if they're out of bounds.
     pub fn replace_range<R: core::ops::RangeBounds<usize>>(&mut self, range: R, string: &str) {
-        //Defense against retarded impl
-        let range_start = range.start_bound();
-        let range_end = range.end_bound();
+        let (start, end) = range_bounds(&range);
+        self.replace_range_bounds(start, end, string)
+    }
 
+    #[inline]
+    ///Validates `range` against content of the string, returning it as normalized byte range.
+    ///
+    ///Range is valid if both its ends are within string, lie on char boundary and start is not
+    ///after end. This is the same validation performed by every range based method (e.g. `replace_range`,
+    ///`remove_range` or `drain`), which panic with equivalent diagnostics instead.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn validate_range<R: core::ops::RangeBounds<usize>>(&self, range: R) -> Result<core::ops::Range<usize>, RangeError> {
+        let (start, end) = range_bounds(&range);
+        check_range(self.as_str(), start, end).map(|(start, end)| start..end)
+    }
+
+    #[inline]
+    ///Removes the specified range in the string, and replaces it with the given string.
+    ///
+    ///Returns error if range is invalid, leaving string unchanged.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn try_replace_range<R: core::ops::RangeBounds<usize>>(&mut self, range: R, string: &str) -> Result<(), RangeError> {
+        let range = self.validate_range(range)?;
+        self.replace_range_bounds(Bound::Included(range.start), Bound::Excluded(range.end), string);
+        Ok(())
+    }
+
+    fn replace_range_bounds(&mut self, start: Bound<usize>, end: Bound<usize>, string: &str) {
         match self {
             Self::Heap(ref mut heap) => {
-                let text = heap.as_str();
-                let (start, _, range_size) = assert_range_len(text, range_start, range_end);
+                let (start, end, range_size) = assert_range_len(heap.as_str(), start, end);
                 if range_size == string.len() {
                     unsafe {
                         ptr::copy(string.as_ptr(), heap.as_mut_ptr().add(start), range_size);
                     }
                 } else {
-                    heap.splice((range_start, range_end), string.bytes());
+                    heap.splice(start..end, string.bytes());
                 }
             },
             Self::Sso(ref mut sso) => {
-                let (start, end, range_size) = assert_range_len(sso.as_str(), range_start, range_end);
-                let required = sso.len() - range_size + string.len();
-                if StrBuf::capacity() < required {
-                    let mut heap = self.assert_heap_from_sso(required);
-                    heap.splice((range_start, range_end), string.bytes());
-                    *self = Self::Heap(heap);
+                let (start, end, range_size) = assert_range_len(sso.as_str(), start, end);
+                let len = sso.len();
+                let required = len - range_size + string.len();
+                if StrBuf::<N>::capacity() < required {
+                    self.promote_to_heap(round_capacity(required)).splice(start..end, string.bytes());
                 } else {
-                    if range_size == string.len() {
-                        unsafe {
-                            ptr::copy(string.as_ptr(), sso.as_mut_ptr().add(start), range_size);
-                        }
-                    } else  {
-                        if let Some(diff) = range_size.checked_sub(string.len()) {
-                            //range_size > string.len()
-                            unsafe {
-                                ptr::copy(sso.as_ptr().add(end), sso.as_mut_ptr().add(start + diff), sso.len() - diff);
-                            }
-                        } else {
-                            let diff = string.len() - range_size;
-                            if let Some(len_diff) = sso.len().checked_sub(diff) {
-                                unsafe {
-                                    ptr::copy(sso.as_ptr().add(start + diff), sso.as_mut_ptr().add(end + diff), len_diff);
-                                }
-                            }
-                        }
-
-                        unsafe {
-                            ptr::copy(string.as_ptr(), sso.as_mut_ptr().add(start), string.len());
-                            sso.set_len(required as _);
-                        }
+                    unsafe {
+                        //Move tail to its new position, then write replacement over freed range.
+                        ptr::copy(sso.as_ptr().add(end), sso.as_mut_ptr().add(start + string.len()), len - end);
+                        ptr::copy_nonoverlapping(string.as_ptr(), sso.as_mut_ptr().add(start), string.len());
+                        set_sso_len(sso, required);
                     }
                 }
             },
         }
     }
 
+    ///Converts vector of bytes into `String`.
+    ///
+    ///Bytes are copied, hence short input is stored inline regardless of vector's capacity.
+    ///
+    ///In case of invalid UTF-8, returns `FromUtf8Error` containing original bytes.
+    pub fn from_utf8(bytes: alloc::vec::Vec<u8>) -> Result<Self, FromUtf8Error> {
+        match core::str::from_utf8(&bytes) {
+            Ok(text) => Ok(Self::new_str(text)),
+            Err(error) => Err(FromUtf8Error {
+                bytes,
+                error,
+            })
+        }
+    }
+
+    #[inline]
+    ///Converts slice of ASCII bytes into `String`.
+    ///
+    ///In case of non-ASCII byte, returns `AsciiError` with its index.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn from_ascii(bytes: &[u8]) -> Result<Self, AsciiError> {
+        let mut res = Self::new();
+        res.push_ascii_str(bytes)?;
+        Ok(res)
+    }
+
     #[inline]
+    ///Converts slice of bytes into `String`, replacing invalid sequences with [REPLACEMENT_CHARACTER](https://doc.rust-lang.org/core/char/constant.REPLACEMENT_CHARACTER.html)
+    pub fn from_utf8_lossy(bytes: &[u8]) -> Self {
+        let mut res = Self::new();
+        res.push_utf8_lossy(bytes);
+        res
+    }
+
+    #[inline]
+    ///Converts slice of bytes into `String` without checking that it is valid UTF-8.
+    ///
+    ///Bytes are copied, stored inline if they fit static buffer.
+    ///
+    ///## Safety
+    ///
+    ///`bytes` must be valid UTF-8.
+    pub unsafe fn from_utf8_unchecked(bytes: &[u8]) -> Self {
+        debug_assert!(core::str::from_utf8(bytes).is_ok());
+        Self::new_str(core::str::from_utf8_unchecked(bytes))
+    }
+
     ///Decodes a UTF-16–encoded sequence into `String`.
     ///
     ///In case of invalid character, returns `DecodeUtf16Error`
     pub fn from_utf16(utf16: &[u16]) -> Result<Self, core::char::DecodeUtf16Error> {
-        let mut res = Self::with_capacity(utf16.len());
-        for ch in char::decode_utf16(utf16.iter().cloned()) {
-            res.push(ch?);
-        }
-
+        let mut res = Self::new();
+        res.push_utf16(utf16)?;
         Ok(res)
     }
 
-    #[inline]
     ///Decodes a UTF-16–encoded sequence into `String`.
     ///
     ///In case of invalid character, replaces it with [REPLACEMENT_CHARACTER](https://doc.rust-lang.org/core/char/constant.REPLACEMENT_CHARACTER.html)
     pub fn from_utf16_lossy(utf16: &[u16]) -> Self {
-        let mut res = Self::with_capacity(utf16.len());
+        let mut res = Self::new();
+        res.push_utf16_lossy(utf16);
+        res
+    }
+
+    ///Decodes a UTF-16–encoded sequence, appending it at the end.
+    ///
+    ///In case of invalid character, returns `DecodeUtf16Error`, truncating string back to its original length.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn push_utf16(&mut self, utf16: &[u16]) -> Result<(), core::char::DecodeUtf16Error> {
+        let len = self.len();
+        self.reserve(utf16.len());
+        for ch in char::decode_utf16(utf16.iter().cloned()) {
+            match ch {
+                Ok(ch) => self.push(ch),
+                Err(error) => {
+                    self.truncate(len);
+                    return Err(error);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    ///Decodes a UTF-16–encoded sequence, appending it at the end.
+    ///
+    ///In case of invalid character, replaces it with [REPLACEMENT_CHARACTER](https://doc.rust-lang.org/core/char/constant.REPLACEMENT_CHARACTER.html)
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn push_utf16_lossy(&mut self, utf16: &[u16]) {
+        self.reserve(utf16.len());
         for ch in char::decode_utf16(utf16.iter().cloned()) {
+            self.push(ch.unwrap_or(core::char::REPLACEMENT_CHARACTER));
+        }
+    }
+
+    #[inline]
+    ///Decodes a UTF-16 little endian encoded bytes into `String`.
+    ///
+    ///In case of odd number of bytes or invalid character, returns `FromUtf16Error`
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn from_utf16le(bytes: &[u8]) -> Result<Self, FromUtf16Error> {
+        Self::from_utf16_bytes(bytes, u16::from_le_bytes)
+    }
+
+    #[inline]
+    ///Decodes a UTF-16 big endian encoded bytes into `String`.
+    ///
+    ///In case of odd number of bytes or invalid character, returns `FromUtf16Error`
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn from_utf16be(bytes: &[u8]) -> Result<Self, FromUtf16Error> {
+        Self::from_utf16_bytes(bytes, u16::from_be_bytes)
+    }
+
+    #[inline]
+    ///Decodes a UTF-16 little endian encoded bytes into `String`.
+    ///
+    ///In case of invalid character or trailing odd byte, replaces it with [REPLACEMENT_CHARACTER](https://doc.rust-lang.org/core/char/constant.REPLACEMENT_CHARACTER.html)
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn from_utf16le_lossy(bytes: &[u8]) -> Self {
+        Self::from_utf16_bytes_lossy(bytes, u16::from_le_bytes)
+    }
+
+    #[inline]
+    ///Decodes a UTF-16 big endian encoded bytes into `String`.
+    ///
+    ///In case of invalid character or trailing odd byte, replaces it with [REPLACEMENT_CHARACTER](https://doc.rust-lang.org/core/char/constant.REPLACEMENT_CHARACTER.html)
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn from_utf16be_lossy(bytes: &[u8]) -> Self {
+        Self::from_utf16_bytes_lossy(bytes, u16::from_be_bytes)
+    }
+
+    fn from_utf16_bytes(bytes: &[u8], to_unit: fn([u8; 2]) -> u16) -> Result<Self, FromUtf16Error> {
+        if (bytes.len() & 1) != 0 {
+            return Err(FromUtf16Error::OddLength);
+        }
+
+        let units = bytes.chunks_exact(2).map(|unit| to_unit([unit[0], unit[1]]));
+        let mut res = Self::with_capacity(bytes.len() / 2);
+        for ch in char::decode_utf16(units) {
+            res.push(ch.map_err(FromUtf16Error::Decode)?);
+        }
+
+        Ok(res)
+    }
+
+    fn from_utf16_bytes_lossy(bytes: &[u8], to_unit: fn([u8; 2]) -> u16) -> Self {
+        let units = bytes.chunks_exact(2);
+        let is_odd = !units.remainder().is_empty();
+        let units = units.map(|unit| to_unit([unit[0], unit[1]]));
+
+        let mut res = Self::with_capacity(bytes.len() / 2);
+        for ch in char::decode_utf16(units) {
             res.push(ch.unwrap_or(core::char::REPLACEMENT_CHARACTER));
         }
+        if is_odd {
+            res.push(core::char::REPLACEMENT_CHARACTER);
+        }
+
+        res
+    }
+
+    ///Decodes a UTF-32–encoded sequence into `String`.
+    ///
+    ///In case of invalid unicode scalar value, returns `Utf32Error` with its index.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn from_utf32(utf32: &[u32]) -> Result<Self, Utf32Error> {
+        let mut required = 0;
+        for (index, value) in utf32.iter().enumerate() {
+            match char::from_u32(*value) {
+                Some(ch) => required += ch.len_utf8(),
+                None => return Err(Utf32Error {
+                    index,
+                    value: *value,
+                }),
+            }
+        }
+
+        let mut res = Self::with_capacity(required);
+        for value in utf32 {
+            //validated above
+            res.push(unsafe { char::from_u32_unchecked(*value) });
+        }
+
+        Ok(res)
+    }
+
+    ///Decodes a UTF-32–encoded sequence into `String`.
+    ///
+    ///In case of invalid unicode scalar value, replaces it with [REPLACEMENT_CHARACTER](https://doc.rust-lang.org/core/char/constant.REPLACEMENT_CHARACTER.html)
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn from_utf32_lossy(utf32: &[u32]) -> Self {
+        #[inline(always)]
+        fn to_char(value: u32) -> char {
+            char::from_u32(value).unwrap_or(core::char::REPLACEMENT_CHARACTER)
+        }
+
+        let required = utf32.iter().map(|value| to_char(*value).len_utf8()).sum();
+        let mut res = Self::with_capacity(required);
+        for value in utf32 {
+            res.push(to_char(*value));
+        }
+
+        res
+    }
+
+    #[cfg(feature = "base64")]
+    #[inline]
+    ///Encodes `bytes` as base64 according to `cfg`.
+    ///
+    ///Output length is computed upfront, hence at most one allocation is performed.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn from_base64_encode(bytes: &[u8], cfg: Base64Config) -> Self {
+        base64::encode(bytes, cfg)
+    }
+
+    ///Creates new string from iterator of fallible items, stopping at the first error.
+    ///
+    ///Equivalent to `iter.into_iter().collect::<Result<String, E>>()`.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn try_from_iter<T, E, I: IntoIterator<Item = Result<T, E>>>(iter: I) -> Result<Self, E> where Self: Extend<T> {
+        let mut res = Self::new();
+        for item in iter {
+            res.extend(core::iter::once(item?));
+        }
+        Ok(res)
+    }
 
+    ///Creates new string by formatting each item via `Display`, separated by `sep`.
+    ///
+    ///Items are formatted directly into storage, without intermediate strings.
+    ///Error returned by item formatting is ignored, leaving whatever was written before it.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn from_display_iter<T: core::fmt::Display, I: IntoIterator<Item = T>>(iter: I, sep: &str) -> Self {
+        let mut res = Self::new();
+        let mut iter = iter.into_iter();
+        if let Some(item) = iter.next() {
+            res.push_display(&item);
+            for item in iter {
+                res.push_str(sep);
+                res.push_display(&item);
+            }
+        }
         res
     }
+
+    ///Creates new string from formatting arguments.
+    ///
+    ///Arguments are formatted twice, first to compute length of output and then into storage
+    ///with exact capacity, which pays off when output consists of many small fragments.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn from_fmt(args: core::fmt::Arguments<'_>) -> Self {
+        match args.as_str() {
+            Some(text) => Self::new_str(text),
+            None => {
+                let mut res = Self::new();
+                let _ = res.write_fmt_sized(args);
+                res
+            }
+        }
+    }
+
+    ///Appends formatted arguments, reserving exact required capacity upfront.
+    ///
+    ///Arguments are formatted twice, first via counting pass and then into storage.
+    ///Use `fmt::Write::write_fmt` to format only once with incremental growth.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn write_fmt_sized(&mut self, args: core::fmt::Arguments<'_>) -> core::fmt::Result {
+        struct LenCounter(usize);
+
+        impl core::fmt::Write for LenCounter {
+            #[inline(always)]
+            fn write_str(&mut self, text: &str) -> core::fmt::Result {
+                self.0 += text.len();
+                Ok(())
+            }
+        }
+
+        let mut counter = LenCounter(0);
+        core::fmt::Write::write_fmt(&mut counter, args)?;
+        self.reserve_exact(counter.0);
+        core::fmt::Write::write_fmt(self, args)
+    }
 }
 
 #[macro_export]
 ///`format` macro to replace std's macro
+///
+///Plain literal is copied as it is and single argument is formatted once, as there is nothing to gain from
+///sizing output upfront.
+///Otherwise output is sized upfront, see `String::from_fmt`.
 macro_rules! format {
-    ($($arg:tt)*) => {{
+    ("{}", $arg:expr $(,)?) => {{
         let mut res = $crate::String::new();
 
-        let _ = core::fmt::Write::write_fmt(&mut res, core::format_args!($($arg)*));
+        let _ = core::fmt::Write::write_fmt(&mut res, core::format_args!("{}", $arg));
         res
-    }}
+    }};
+    ($($arg:tt)*) => {
+        $crate::String::from_fmt(core::format_args!($($arg)*))
+    };
 }
@@ -0,0 +1,58 @@
+use crate::{SmallString, SSO_MAX_SIZE};
+use core::iter::FusedIterator;
+
+///Consuming iterator over lines of `String`
+///
+///Source string is released as soon as the last line is yielded.
+pub struct IntoLines<const N: usize = SSO_MAX_SIZE> {
+    pub(crate) string: SmallString<N>,
+    pub(crate) cursor: usize,
+}
+
+impl<const N: usize> IntoLines<N> {
+    #[inline]
+    ///Returns the remaining sub-string of this iterator.
+    pub fn as_str(&self) -> &str {
+        unsafe {
+            self.string.as_str().get_unchecked(self.cursor..)
+        }
+    }
+}
+
+impl<const N: usize> Iterator for IntoLines<N> {
+    type Item = SmallString<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.as_str();
+        if remaining.is_empty() {
+            return None;
+        }
+
+        let (line, consumed) = match remaining.find('\n') {
+            Some(idx) => match remaining[..idx].strip_suffix('\r') {
+                Some(line) => (line, idx + 1),
+                None => (&remaining[..idx], idx + 1),
+            },
+            None => (remaining, remaining.len()),
+        };
+        let line = SmallString::new_str(line);
+
+        self.cursor += consumed;
+        if self.cursor == self.string.len() {
+            self.string = SmallString::new();
+            self.cursor = 0;
+        }
+
+        Some(line)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.as_str().len() {
+            0 => (0, Some(0)),
+            len => (1, Some(len)),
+        }
+    }
+}
+
+impl<const N: usize> FusedIterator for IntoLines<N> {}
@@ -0,0 +1,132 @@
+use core::fmt;
+
+///Formatting wrapper over bytes that are expected to be UTF-8.
+///
+///Valid UTF-8 is written as it is, while each invalid byte is written as `\xNN`.
+///
+///Created by [lossy](fn.lossy.html).
+#[derive(Clone, Copy)]
+pub struct Lossy<'a> {
+    bytes: &'a [u8],
+}
+
+///Wraps `bytes` to format them as text without allocation.
+///
+///Valid UTF-8 is written as it is, while each invalid byte is written as `\xNN`.
+#[inline(always)]
+pub const fn lossy(bytes: &[u8]) -> Lossy<'_> {
+    Lossy {
+        bytes
+    }
+}
+
+impl Lossy<'_> {
+    fn write_with(&self, f: &mut fmt::Formatter<'_>, write_valid: fn(&str, &mut fmt::Formatter<'_>) -> fmt::Result) -> fmt::Result {
+        for chunk in utf8_chunks(self.bytes) {
+            write_valid(chunk.valid(), f)?;
+            for byte in chunk.invalid() {
+                write!(f, "\\x{:02X}", byte)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+///Chunk of bytes, consisting of valid UTF-8 followed by invalid sequence.
+///
+///Created by [Utf8Chunks](struct.Utf8Chunks.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Utf8Chunk<'a> {
+    valid: &'a str,
+    invalid: &'a [u8],
+}
+
+impl<'a> Utf8Chunk<'a> {
+    #[inline(always)]
+    ///Returns valid UTF-8 part of the chunk, which might be empty.
+    pub const fn valid(&self) -> &'a str {
+        self.valid
+    }
+
+    #[inline(always)]
+    ///Returns invalid sequence following valid part.
+    ///
+    ///It is empty only for the last chunk, otherwise it contains at most 3 bytes, unless
+    ///bytes end with truncated character, in which case it contains all of its bytes.
+    pub const fn invalid(&self) -> &'a [u8] {
+        self.invalid
+    }
+}
+
+///Iterator over chunks of bytes, splitting them into valid UTF-8 and invalid sequences.
+///
+///Created by [utf8_chunks](fn.utf8_chunks.html).
+#[derive(Clone)]
+pub struct Utf8Chunks<'a> {
+    bytes: &'a [u8],
+}
+
+///Creates iterator over `bytes`, splitting them into valid UTF-8 and invalid sequences.
+///
+///Each invalid sequence corresponds to a single replacement character in lossy decoding.
+#[inline(always)]
+pub const fn utf8_chunks(bytes: &[u8]) -> Utf8Chunks<'_> {
+    Utf8Chunks {
+        bytes
+    }
+}
+
+impl<'a> Iterator for Utf8Chunks<'a> {
+    type Item = Utf8Chunk<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+
+        match core::str::from_utf8(self.bytes) {
+            Ok(valid) => {
+                self.bytes = &[];
+                Some(Utf8Chunk {
+                    valid,
+                    invalid: &[],
+                })
+            },
+            Err(error) => {
+                let (valid, rest) = self.bytes.split_at(error.valid_up_to());
+                let (invalid, rest) = rest.split_at(error.error_len().unwrap_or(rest.len()));
+                self.bytes = rest;
+                Some(Utf8Chunk {
+                    valid: unsafe {
+                        core::str::from_utf8_unchecked(valid)
+                    },
+                    invalid,
+                })
+            }
+        }
+    }
+}
+
+impl core::iter::FusedIterator for Utf8Chunks<'_> {}
+
+impl fmt::Display for Lossy<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_with(f, |text, f| f.write_str(text))
+    }
+}
+
+impl fmt::Debug for Lossy<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+        self.write_with(f, |text, f| {
+            for ch in text.chars() {
+                fmt::Display::fmt(&ch.escape_debug(), f)?;
+            }
+            Ok(())
+        })?;
+        f.write_str("\"")
+    }
+}
@@ -0,0 +1,119 @@
+//! Pool of reusable strings.
+
+use crate::{SmallString, SSO_MAX_SIZE};
+use alloc::vec::Vec;
+
+///Pool of cleared heap allocated strings, re-using their storage.
+///
+///Pools `String` by default, other inline capacities are selected with `N`.
+///
+///Inline strings are never pooled, as there is no storage to re-use.
+///
+///Pool performs no synchronization, hence it should be wrapped by user when shared.
+pub struct StringPool<const N: usize = SSO_MAX_SIZE> {
+    strings: Vec<SmallString<N>>,
+    max_len: usize,
+    max_capacity: usize,
+}
+
+impl StringPool {
+    #[inline]
+    ///Creates new empty pool of `String`, storing at most `max_len` strings.
+    ///
+    ///Strings returned to pool are shrunk to have capacity at most `max_capacity`.
+    pub const fn new(max_len: usize, max_capacity: usize) -> Self {
+        Self::new_small(max_len, max_capacity)
+    }
+
+    #[inline]
+    ///Creates new empty pool of `String`, with storage pre-allocated for `max_len` strings.
+    ///
+    ///Strings returned to pool are shrunk to have capacity at most `max_capacity`.
+    pub fn with_capacity(max_len: usize, max_capacity: usize) -> Self {
+        Self::with_capacity_small(max_len, max_capacity)
+    }
+}
+
+impl<const N: usize> StringPool<N> {
+    #[inline]
+    ///Creates new empty pool of `SmallString<N>`, storing at most `max_len` strings.
+    ///
+    ///Strings returned to pool are shrunk to have capacity at most `max_capacity`.
+    pub const fn new_small(max_len: usize, max_capacity: usize) -> Self {
+        Self {
+            strings: Vec::new(),
+            max_len,
+            max_capacity,
+        }
+    }
+
+    #[inline]
+    ///Creates new empty pool of `SmallString<N>`, with storage pre-allocated for `max_len` strings.
+    ///
+    ///Strings returned to pool are shrunk to have capacity at most `max_capacity`.
+    pub fn with_capacity_small(max_len: usize, max_capacity: usize) -> Self {
+        Self {
+            strings: Vec::with_capacity(max_len),
+            max_len,
+            max_capacity,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns number of strings in pool.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    #[inline(always)]
+    ///Returns whether pool has no strings.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    #[inline(always)]
+    ///Returns maximum number of strings stored by pool.
+    pub const fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    #[inline(always)]
+    ///Returns maximum capacity of strings stored by pool.
+    pub const fn max_capacity(&self) -> usize {
+        self.max_capacity
+    }
+
+    #[inline]
+    ///Returns empty string from pool, or creates new one if pool is empty.
+    pub fn get(&mut self) -> SmallString<N> {
+        self.strings.pop().unwrap_or_default()
+    }
+
+    #[inline]
+    ///Returns empty string with at least `capacity`, taking it from pool if possible.
+    pub fn get_with_capacity(&mut self, capacity: usize) -> SmallString<N> {
+        match self.strings.pop() {
+            Some(mut string) => {
+                string.reserve(capacity);
+                string
+            },
+            None => SmallString::with_capacity(capacity),
+        }
+    }
+
+    ///Returns string to the pool, clearing it.
+    ///
+    ///String is dropped if it is not heap allocated or pool is full.
+    pub fn put(&mut self, mut string: SmallString<N>) {
+        if !string.is_alloc() || self.strings.len() >= self.max_len {
+            return;
+        }
+
+        string.clear();
+        string.shrink_to(self.max_capacity);
+        //Small enough `max_capacity` moves string back inline, leaving nothing to re-use.
+        if string.is_alloc() {
+            self.strings.push(string);
+        }
+    }
+}
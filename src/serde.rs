@@ -1,40 +1,362 @@
+//! Serde integration.
+
 use serde::de::{Deserialize, Deserializer};
 use serde::ser::{Serialize, Serializer};
 
-use crate::String;
+use crate::{String, SmallString};
 
-impl Serialize for String {
+impl<const N: usize> Serialize for SmallString<N> {
     fn serialize<SER: Serializer>(&self, ser: SER) -> Result<SER::Ok, SER::Error> {
         ser.serialize_str(self.as_str())
     }
 }
 
-struct StringVisitor;
+struct StringVisitor<const N: usize>;
 
-impl<'de> serde::de::Visitor<'de> for StringVisitor {
-    type Value = String;
+impl<'de, const N: usize> serde::de::Visitor<'de> for StringVisitor<N> {
+    type Value = SmallString<N>;
 
     #[inline(always)]
     fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
-        formatter.write_str("a string")
+        formatter.write_str("a UTF-8 string")
     }
 
     #[inline]
     fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
-        Ok(String::new_str(v))
+        Ok(SmallString::new_str(v))
+    }
+
+    #[inline]
+    fn visit_char<E: serde::de::Error>(self, v: char) -> Result<Self::Value, E> {
+        Ok(SmallString::from(v))
+    }
+
+    #[inline]
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        match core::str::from_utf8(v) {
+            Ok(v) => Ok(SmallString::new_str(v)),
+            Err(_) => Err(E::invalid_value(serde::de::Unexpected::Bytes(v), &self)),
+        }
     }
 }
 
-impl<'de> Deserialize<'de> for String {
+impl<'de, const N: usize> Deserialize<'de> for SmallString<N> {
     #[inline]
     fn deserialize<D: Deserializer<'de>>(des: D) -> Result<Self, D::Error> {
         des.deserialize_str(StringVisitor)
     }
 }
 
+///`DeserializeSeed` producing `String` through user callback.
+///
+///Callback is invoked with every deserialized string, allowing to return cached instance for
+///values that were seen previously (e.g. repeated map keys).
+///
+///Pass `&mut callback` to re-use the same callback across multiple values, such as with
+///`SeqAccess::next_element_seed`.
+///
+///## Note
+///
+///This API is not part of `String` original API.
+pub struct StringSeed<F> {
+    cb: F,
+}
+
+impl<F: FnMut(&str) -> String> StringSeed<F> {
+    #[inline(always)]
+    ///Creates new seed with provided callback.
+    pub const fn new(cb: F) -> Self {
+        Self {
+            cb
+        }
+    }
+}
+
+impl<'de, F: FnMut(&str) -> String> serde::de::DeserializeSeed<'de> for StringSeed<F> {
+    type Value = String;
+
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(self, des: D) -> Result<Self::Value, D::Error> {
+        des.deserialize_str(self)
+    }
+}
+
+impl<'de, F: FnMut(&str) -> String> serde::de::Visitor<'de> for StringSeed<F> {
+    type Value = String;
+
+    #[inline(always)]
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a UTF-8 string")
+    }
+
+    #[inline]
+    fn visit_str<E: serde::de::Error>(mut self, v: &str) -> Result<Self::Value, E> {
+        Ok((self.cb)(v))
+    }
+
+    #[inline]
+    fn visit_char<E: serde::de::Error>(mut self, v: char) -> Result<Self::Value, E> {
+        let mut buf = [0u8; 4];
+        Ok((self.cb)(v.encode_utf8(&mut buf)))
+    }
+
+    #[inline]
+    fn visit_bytes<E: serde::de::Error>(mut self, v: &[u8]) -> Result<Self::Value, E> {
+        match core::str::from_utf8(v) {
+            Ok(text) => Ok((self.cb)(text)),
+            Err(_) => Err(E::invalid_value(serde::de::Unexpected::Bytes(v), &self)),
+        }
+    }
+}
+
+impl Serialize for crate::types::NonEmptyString {
+    #[inline(always)]
+    fn serialize<SER: Serializer>(&self, ser: SER) -> Result<SER::Ok, SER::Error> {
+        ser.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for crate::types::NonEmptyString {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(des: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(des)?;
+        Self::new(string).map_err(|_| serde::de::Error::invalid_length(0, &"a non-empty string"))
+    }
+}
+
+fn write_display<T: core::fmt::Display>(value: T) -> String {
+    let mut res = String::new();
+    let _ = core::fmt::Write::write_fmt(&mut res, format_args!("{}", value));
+    res
+}
+
+struct LenientVisitor;
+
+impl<'de> serde::de::Visitor<'de> for LenientVisitor {
+    type Value = String;
+
+    #[inline(always)]
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a string, number or boolean")
+    }
+
+    #[inline]
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(String::new_str(v))
+    }
+
+    #[inline]
+    fn visit_bool<E: serde::de::Error>(self, v: bool) -> Result<Self::Value, E> {
+        match v {
+            true => Ok(String::new_sso("true")),
+            false => Ok(String::new_sso("false")),
+        }
+    }
+
+    #[inline]
+    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(write_display(v))
+    }
+
+    #[inline]
+    fn visit_i128<E: serde::de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        Ok(write_display(v))
+    }
+
+    #[inline]
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(write_display(v))
+    }
+
+    #[inline]
+    fn visit_u128<E: serde::de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        Ok(write_display(v))
+    }
+
+    #[inline]
+    fn visit_f32<E: serde::de::Error>(self, v: f32) -> Result<Self::Value, E> {
+        Ok(write_display(v))
+    }
+
+    #[inline]
+    fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(write_display(v))
+    }
+}
+
+///Lenient deserialization, accepting numbers and booleans in place of string.
+///
+///Non-string values are formatted using their `Display` implementation.
+///Serialization is the same as for `String`.
+///
+///Usage: `#[serde(with = "stroka::serde::lenient")]`
+pub mod lenient {
+    use super::*;
+
+    #[inline(always)]
+    ///Serializes string as it is.
+    pub fn serialize<SER: Serializer>(value: &String, ser: SER) -> Result<SER::Ok, SER::Error> {
+        value.serialize(ser)
+    }
+
+    #[inline]
+    ///Deserializes string, number or boolean as `String`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(des: D) -> Result<String, D::Error> {
+        des.deserialize_any(LenientVisitor)
+    }
+}
+
+///Lenient deserialization of optional string, accepting numbers and booleans in place of string.
+///
+///Usage: `#[serde(with = "stroka::serde::lenient_option")]`
+pub mod lenient_option {
+    use super::*;
+
+    struct OptionVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for OptionVisitor {
+        type Value = Option<String>;
+
+        #[inline(always)]
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("an optional string, number or boolean")
+        }
+
+        #[inline(always)]
+        fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        #[inline(always)]
+        fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        #[inline]
+        fn visit_some<D: Deserializer<'de>>(self, des: D) -> Result<Self::Value, D::Error> {
+            super::lenient::deserialize(des).map(Some)
+        }
+    }
+
+    #[inline(always)]
+    ///Serializes optional string as it is.
+    pub fn serialize<SER: Serializer>(value: &Option<String>, ser: SER) -> Result<SER::Ok, SER::Error> {
+        value.serialize(ser)
+    }
+
+    #[inline]
+    ///Deserializes optional string, number or boolean as `Option<String>`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(des: D) -> Result<Option<String>, D::Error> {
+        des.deserialize_option(OptionVisitor)
+    }
+}
+
+struct TrimmedVisitor;
+
+impl<'de> serde::de::Visitor<'de> for TrimmedVisitor {
+    type Value = String;
+
+    #[inline(always)]
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a UTF-8 string")
+    }
+
+    #[inline]
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(String::new_str(v.trim()))
+    }
+
+    #[inline]
+    fn visit_char<E: serde::de::Error>(self, v: char) -> Result<Self::Value, E> {
+        let mut buf = [0u8; 4];
+        self.visit_str(v.encode_utf8(&mut buf))
+    }
+
+    #[inline]
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        match core::str::from_utf8(v) {
+            Ok(text) => self.visit_str(text),
+            Err(_) => Err(E::invalid_value(serde::de::Unexpected::Bytes(v), &self)),
+        }
+    }
+}
+
+///Deserialization with leading and trailing Unicode whitespace removed.
+///
+///Only trimmed part of input is copied into `String`.
+///Serialization is the same as for `String`.
+///
+///Usage: `#[serde(with = "stroka::serde::trimmed")]`
+pub mod trimmed {
+    use super::*;
+
+    #[inline(always)]
+    ///Serializes string as it is.
+    pub fn serialize<SER: Serializer>(value: &String, ser: SER) -> Result<SER::Ok, SER::Error> {
+        value.serialize(ser)
+    }
+
+    #[inline]
+    ///Deserializes string, removing leading and trailing whitespace.
+    pub fn deserialize<'de, D: Deserializer<'de>>(des: D) -> Result<String, D::Error> {
+        des.deserialize_str(TrimmedVisitor)
+    }
+}
+
+///Deserialization of optional string with leading and trailing Unicode whitespace removed.
+///
+///Whitespace-only string is deserialized as `None`.
+///
+///Usage: `#[serde(with = "stroka::serde::trimmed_opt")]`
+pub mod trimmed_opt {
+    use super::*;
+
+    struct OptionVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for OptionVisitor {
+        type Value = Option<String>;
+
+        #[inline(always)]
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("an optional UTF-8 string")
+        }
+
+        #[inline(always)]
+        fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        #[inline(always)]
+        fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        #[inline]
+        fn visit_some<D: Deserializer<'de>>(self, des: D) -> Result<Self::Value, D::Error> {
+            super::trimmed::deserialize(des).map(|value| match value.is_empty() {
+                true => None,
+                false => Some(value),
+            })
+        }
+    }
+
+    #[inline(always)]
+    ///Serializes optional string as it is.
+    pub fn serialize<SER: Serializer>(value: &Option<String>, ser: SER) -> Result<SER::Ok, SER::Error> {
+        value.serialize(ser)
+    }
+
+    #[inline]
+    ///Deserializes optional string, removing leading and trailing whitespace.
+    pub fn deserialize<'de, D: Deserializer<'de>>(des: D) -> Result<Option<String>, D::Error> {
+        des.deserialize_option(OptionVisitor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::String;
+    use alloc::string::ToString;
 
     use serde::de::Deserialize;
     use serde::de::value::{BorrowedStrDeserializer, Error as ValueError};
@@ -48,6 +370,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "sso-only"))]
     fn should_deserialize_outside_sso_cap() {
         const TEXT: &str = "lolka lol lolid by loli";
         let des = BorrowedStrDeserializer::<ValueError>::new(TEXT);
@@ -55,4 +378,294 @@ mod tests {
         assert_eq!(res.as_str(), TEXT);
         assert!(res.is_alloc());
     }
+
+    #[test]
+    fn should_deserialize_utf8_bytes() {
+        use serde::de::value::BytesDeserializer;
+
+        let res = String::deserialize(BytesDeserializer::<ValueError>::new("単語".as_bytes())).expect("to deserialize");
+        assert_eq!(res, "単語");
+
+        let error = String::deserialize(BytesDeserializer::<ValueError>::new(&[0xff, 0xfe])).expect_err("to reject invalid UTF-8");
+        assert!(error.to_string().contains("a UTF-8 string"));
+    }
+
+    #[test]
+    fn should_round_trip_small_string() {
+        type Path = crate::SmallString<22>;
+        type Tiny = crate::SmallString<6>;
+
+        let res: Path = serde_json::from_str(r#""lolka lol lolid by lo""#).expect("to deserialize");
+        assert_eq!(res, "lolka lol lolid by lo");
+        assert!(!res.is_alloc());
+        assert_eq!(serde_json::to_string(&res).expect("to serialize"), r#""lolka lol lolid by lo""#);
+
+        let res: Tiny = serde_json::from_str(r#""lolka""#).expect("to deserialize");
+        assert_eq!(res, "lolka");
+        assert!(!res.is_alloc());
+        let error = serde_json::from_str::<Tiny>("1").expect_err("to reject number");
+        assert!(error.to_string().contains("a UTF-8 string"));
+    }
+
+    #[test]
+    #[cfg(not(feature = "sso-only"))]
+    fn should_deserialize_char_exceeding_small_string() {
+        use serde::de::value::CharDeserializer;
+
+        let res = crate::SmallString::<2>::deserialize(CharDeserializer::<ValueError>::new('❤')).expect("to deserialize");
+        assert_eq!(res, "❤");
+        assert!(res.is_alloc());
+    }
+
+    #[derive(serde::Deserialize, serde::Serialize, Debug, PartialEq)]
+    struct Keyed {
+        map: alloc::collections::BTreeMap<String, String>,
+        boxed: alloc::boxed::Box<String>,
+        opt: Option<String>,
+        #[serde(default)]
+        defaulted: String,
+    }
+
+    #[test]
+    fn should_round_trip_string_in_containers() {
+        let mut map = alloc::collections::BTreeMap::new();
+        map.insert(String::new_str("key"), String::new_str("value"));
+        map.insert(String::new_str("単語"), String::new());
+        let value = Keyed {
+            map,
+            boxed: alloc::boxed::Box::new(String::new_str("boxed")),
+            opt: Some(String::new_str("opt")),
+            defaulted: String::new_str("defaulted"),
+        };
+
+        let json = serde_json::to_string(&value).expect("to serialize");
+        assert_eq!(serde_json::from_str::<Keyed>(&json).expect("to deserialize"), value);
+
+        let bytes = postcard::to_allocvec(&value).expect("to serialize");
+        assert_eq!(postcard::from_bytes::<Keyed>(&bytes).expect("to deserialize"), value);
+
+        let res: Keyed = serde_json::from_str(r#"{"map":{},"boxed":"","opt":null}"#).expect("to deserialize");
+        assert!(res.opt.is_none());
+        assert!(res.defaulted.is_empty());
+    }
+
+    //Deserializer is not told which field it deserializes, hence error describes only value and its position.
+    #[test]
+    fn should_report_expected_string_on_invalid_value() {
+        let error = serde_json::from_str::<Keyed>(r#"{"map":{},"boxed":1,"opt":null}"#).expect_err("to reject number");
+        assert_eq!(error.to_string(), "invalid type: integer `1`, expected a UTF-8 string at line 1 column 19");
+
+        let error = serde_json::from_str::<Keyed>(r#"{"map":{"key":[]},"boxed":"","opt":null}"#).expect_err("to reject array");
+        assert_eq!(error.to_string(), "invalid type: sequence, expected a UTF-8 string at line 1 column 14");
+    }
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    #[serde(untagged)]
+    enum Untagged {
+        Number(u32),
+        Text(Option<String>),
+    }
+
+    #[test]
+    fn should_deserialize_option_in_untagged_enum() {
+        assert_eq!(serde_json::from_str::<Untagged>("1").expect("to deserialize"), Untagged::Number(1));
+        assert_eq!(serde_json::from_str::<Untagged>(r#""lolka""#).expect("to deserialize"), Untagged::Text(Some(String::new_str("lolka"))));
+        assert_eq!(serde_json::from_str::<Untagged>("null").expect("to deserialize"), Untagged::Text(None));
+        serde_json::from_str::<Untagged>("[]").expect_err("to reject array");
+    }
+
+    struct RepeatedSeq<'a, F>(&'a mut F);
+
+    impl<'de, 'a, F: FnMut(&str) -> String> serde::de::Visitor<'de> for RepeatedSeq<'a, F> {
+        type Value = alloc::vec::Vec<String>;
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("a sequence of strings")
+        }
+
+        fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut res = alloc::vec::Vec::new();
+            while let Some(value) = seq.next_element_seed(crate::serde::StringSeed::new(&mut *self.0))? {
+                res.push(value);
+            }
+            Ok(res)
+        }
+    }
+
+    #[test]
+    fn should_deserialize_with_string_seed() {
+        use serde::de::Deserializer;
+
+        let mut seen = alloc::vec::Vec::<String>::new();
+        let mut calls = 0;
+        let mut cb = |text: &str| {
+            calls += 1;
+            match seen.iter().find(|seen| *seen == text) {
+                Some(cached) => cached.clone(),
+                None => {
+                    let value = String::new_str(text);
+                    seen.push(value.clone());
+                    value
+                },
+            }
+        };
+
+        let mut des = serde_json::Deserializer::from_str(r#"["id","name","id","単語","name","id"]"#);
+        let res = des.deserialize_seq(RepeatedSeq(&mut cb)).expect("to deserialize");
+        assert_eq!(res, ["id", "name", "id", "単語", "name", "id"]);
+        assert_eq!(calls, 6);
+        assert_eq!(seen, ["id", "name", "単語"]);
+
+        let mut des = serde_json::Deserializer::from_str("[1]");
+        let error = des.deserialize_seq(RepeatedSeq(&mut |text: &str| String::new_str(text))).expect_err("to reject number");
+        assert!(error.to_string().contains("a UTF-8 string"));
+    }
+
+    #[derive(serde::Deserialize, serde::Serialize, Debug)]
+    struct Config {
+        name: crate::types::NonEmptyString,
+    }
+
+    #[test]
+    fn should_deserialize_non_empty_string() {
+        let res: Config = serde_json::from_str(r#"{"name":"lolka"}"#).expect("to deserialize");
+        assert_eq!(res.name, "lolka");
+        assert_eq!(serde_json::to_string(&res).expect("to serialize"), r#"{"name":"lolka"}"#);
+
+        let error = serde_json::from_str::<Config>(r#"{"name":""}"#).expect_err("to reject empty");
+        let error = error.to_string();
+        assert!(error.contains("a non-empty string"), "{}", error);
+        serde_json::from_str::<Config>(r#"{"name":1}"#).expect_err("to reject number");
+    }
+
+    #[derive(serde::Deserialize, serde::Serialize, Debug)]
+    struct Lenient {
+        #[serde(with = "crate::serde::lenient")]
+        value: String,
+        #[serde(with = "crate::serde::lenient_option", default)]
+        opt: Option<String>,
+    }
+
+    #[test]
+    fn should_deserialize_lenient_values() {
+        const CASES: [(&str, &str); 6] = [
+            (r#"{"value":"lolka"}"#, "lolka"),
+            (r#"{"value":8080}"#, "8080"),
+            (r#"{"value":-1}"#, "-1"),
+            (r#"{"value":1.5}"#, "1.5"),
+            (r#"{"value":true}"#, "true"),
+            (r#"{"value":false}"#, "false"),
+        ];
+
+        for (json, expected) in CASES {
+            let res: Lenient = serde_json::from_str(json).expect("to deserialize");
+            assert_eq!(res.value, expected);
+            assert!(!res.value.is_alloc());
+            assert!(res.opt.is_none());
+        }
+    }
+
+    #[test]
+    fn should_deserialize_lenient_f32_without_widening() {
+        use serde::de::value::F32Deserializer;
+
+        let res = crate::serde::lenient::deserialize(F32Deserializer::<ValueError>::new(0.1f32)).expect("to deserialize");
+        assert_eq!(res, "0.1");
+        let res = crate::serde::lenient::deserialize(F32Deserializer::<ValueError>::new(-2.5f32)).expect("to deserialize");
+        assert_eq!(res, "-2.5");
+    }
+
+    #[test]
+    #[cfg(not(feature = "sso-only"))]
+    fn should_deserialize_lenient_values_outside_sso_cap() {
+        let res: Lenient = serde_json::from_str(r#"{"value":18446744073709551615}"#).expect("to deserialize");
+        assert_eq!(res.value, "18446744073709551615");
+        assert!(res.value.is_alloc());
+    }
+
+    #[test]
+    fn should_deserialize_lenient_option() {
+        let res: Lenient = serde_json::from_str(r#"{"value":"","opt":null}"#).expect("to deserialize");
+        assert!(res.opt.is_none());
+        let res: Lenient = serde_json::from_str(r#"{"value":"","opt":443}"#).expect("to deserialize");
+        assert_eq!(res.opt.as_deref(), Some("443"));
+        let res: Lenient = serde_json::from_str(r#"{"value":"","opt":"lolka"}"#).expect("to deserialize");
+        assert_eq!(res.opt.as_deref(), Some("lolka"));
+
+        let res = serde_json::to_string(&res).expect("to serialize");
+        assert_eq!(res, r#"{"value":"","opt":"lolka"}"#);
+    }
+
+    #[test]
+    fn should_reject_lenient_non_scalar() {
+        serde_json::from_str::<Lenient>(r#"{"value":null}"#).expect_err("to reject null");
+        serde_json::from_str::<Lenient>(r#"{"value":[1]}"#).expect_err("to reject array");
+        serde_json::from_str::<Lenient>(r#"{"value":{}}"#).expect_err("to reject object");
+        serde_json::from_str::<Lenient>(r#"{"value":"","opt":[1]}"#).expect_err("to reject array");
+    }
+
+    #[derive(serde::Deserialize, serde::Serialize, Debug)]
+    struct Trimmed {
+        #[serde(with = "crate::serde::trimmed")]
+        value: String,
+        #[serde(with = "crate::serde::trimmed_opt", default)]
+        opt: Option<String>,
+    }
+
+    #[test]
+    fn should_deserialize_trimmed_values() {
+        const CASES: [(&str, &str); 5] = [
+            (r#"{"value":" lolka "}"#, "lolka"),
+            (r#"{"value":"\t単語\n"}"#, "単語"),
+            (r#"{"value":"\u3000lol ka\u00a0"}"#, "lol ka"),
+            (r#"{"value":"   "}"#, ""),
+            (r#"{"value":"lolka"}"#, "lolka"),
+        ];
+
+        for (json, expected) in CASES {
+            let res: Trimmed = serde_json::from_str(json).expect("to deserialize");
+            assert_eq!(res.value, expected);
+            assert!(res.opt.is_none());
+        }
+        serde_json::from_str::<Trimmed>(r#"{"value":1}"#).expect_err("to reject number");
+    }
+
+    #[test]
+    #[cfg(not(feature = "sso-only"))]
+    fn should_copy_only_trimmed_part() {
+        let res: Trimmed = serde_json::from_str(r#"{"value":"          lolka          "}"#).expect("to deserialize");
+        assert_eq!(res.value, "lolka");
+        assert!(!res.value.is_alloc());
+    }
+
+    #[test]
+    fn should_deserialize_trimmed_option() {
+        let res: Trimmed = serde_json::from_str(r#"{"value":"","opt":null}"#).expect("to deserialize");
+        assert!(res.opt.is_none());
+        let res: Trimmed = serde_json::from_str(r#"{"value":"","opt":" \t "}"#).expect("to deserialize");
+        assert!(res.opt.is_none());
+        let res: Trimmed = serde_json::from_str(r#"{"value":"","opt":""}"#).expect("to deserialize");
+        assert!(res.opt.is_none());
+        let res: Trimmed = serde_json::from_str(r#"{"value":"","opt":" lolka "}"#).expect("to deserialize");
+        assert_eq!(res.opt.as_deref(), Some("lolka"));
+    }
+
+    #[test]
+    fn should_serialize_trimmed_as_it_is() {
+        let value = Trimmed {
+            value: String::new_str(" lolka "),
+            opt: Some(String::new_str("単語 ")),
+        };
+        let expected = r#"{"value":" lolka ","opt":"単語 "}"#;
+        assert_eq!(serde_json::to_string(&value).expect("to serialize"), expected);
+
+        let res: Trimmed = serde_json::from_str(r#"{"value":"lolka","opt":"単語"}"#).expect("to deserialize");
+        assert_eq!(serde_json::to_string(&res).expect("to serialize"), r#"{"value":"lolka","opt":"単語"}"#);
+
+        let value = Trimmed {
+            value: String::new(),
+            opt: None,
+        };
+        assert_eq!(serde_json::to_string(&value).expect("to serialize"), r#"{"value":"","opt":null}"#);
+    }
 }
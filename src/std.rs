@@ -1,14 +1,178 @@
 extern crate std;
 
-use crate::String;
+use crate::SmallString;
 
 use alloc::vec;
 use std::io;
 use std::net::{ToSocketAddrs, SocketAddr};
-use std::ffi::OsStr;
-use std::path::Path;
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
 
-impl ToSocketAddrs for String {
+impl<const N: usize> SmallString<N> {
+    #[inline]
+    ///Decodes wide (UTF-16) string, as returned by Windows APIs, into `String`.
+    ///
+    ///In case of invalid character, returns `DecodeUtf16Error`
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn from_wide(units: &[u16]) -> Result<Self, core::char::DecodeUtf16Error> {
+        Self::from_utf16(units)
+    }
+
+    #[inline]
+    ///Decodes wide (UTF-16) string, as returned by Windows APIs, into `String`.
+    ///
+    ///In case of invalid character, replaces it with [REPLACEMENT_CHARACTER](https://doc.rust-lang.org/core/char/constant.REPLACEMENT_CHARACTER.html)
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn from_wide_lossy(units: &[u16]) -> Self {
+        Self::from_utf16_lossy(units)
+    }
+
+    #[inline]
+    ///Decodes NUL terminated wide (UTF-16) string into `String`.
+    ///
+    ///Decoding stops at first `0` unit, which is not included in result.
+    ///If there is no terminator, whole slice is decoded.
+    ///
+    ///In case of invalid character, returns `DecodeUtf16Error`
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn from_wide_nul(units: &[u16]) -> Result<Self, core::char::DecodeUtf16Error> {
+        let len = units.iter().position(|unit| *unit == 0).unwrap_or(units.len());
+        Self::from_utf16(&units[..len])
+    }
+
+    #[inline]
+    ///Converts OS string into `String`, returning `None` if it is not valid Unicode.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn from_os_str(text: &OsStr) -> Option<Self> {
+        text.to_str().map(Self::new_str)
+    }
+
+    #[inline]
+    ///Converts OS string into `String`, replacing invalid sequences with [REPLACEMENT_CHARACTER](https://doc.rust-lang.org/core/char/constant.REPLACEMENT_CHARACTER.html)
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn from_os_str_lossy(text: &OsStr) -> Self {
+        Self::new_str(&text.to_string_lossy())
+    }
+
+    #[inline]
+    ///Creates NUL terminated copy of string.
+    ///
+    ///In case of interior NUL byte, returns `NulError` with its position.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn to_c_string(&self) -> Result<std::ffi::CString, std::ffi::NulError> {
+        std::ffi::CString::new(self.as_bytes())
+    }
+
+    #[inline]
+    ///Writes whole content of string into `writer`.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn write_to<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(self.as_bytes())
+    }
+
+    ///Writes `prefix` followed by whole content of string into `writer`.
+    ///
+    ///Uses vectored write, looping until both `prefix` and string are written completely.
+    ///
+    ///## Note
+    ///
+    ///This API is not part of `String` original API.
+    pub fn write_to_with<W: io::Write>(&self, prefix: &[u8], writer: &mut W) -> io::Result<()> {
+        let mut prefix = prefix;
+        let mut body = self.as_bytes();
+
+        while !prefix.is_empty() {
+            let bufs = [io::IoSlice::new(prefix), io::IoSlice::new(body)];
+            match writer.write_vectored(&bufs) {
+                Ok(0) => return Err(io::Error::new(io::ErrorKind::WriteZero, "failed to write whole buffer")),
+                Ok(written) => match written.checked_sub(prefix.len()) {
+                    Some(written) => {
+                        prefix = &[];
+                        body = &body[written..];
+                    },
+                    None => prefix = &prefix[written..],
+                },
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            }
+        }
+
+        writer.write_all(body)
+    }
+}
+
+///Writes UTF-8 text into string.
+///
+///Trailing incomplete character is not consumed, hence `write` returns number of bytes preceding it.
+///Invalid UTF-8 is rejected with `ErrorKind::InvalidData`, once valid bytes preceding it are written.
+///
+///String has no room to carry incomplete character over to the next call, hence character split between two
+///`write_all` calls is rejected as invalid. For input arriving in arbitrary chunks, use `Utf8Appender` instead.
+impl<const N: usize> io::Write for SmallString<N> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let valid_len = match core::str::from_utf8(buf) {
+            Ok(_) => buf.len(),
+            Err(error) if error.valid_up_to() > 0 => error.valid_up_to(),
+            Err(error) => return Err(io::Error::new(io::ErrorKind::InvalidData, error)),
+        };
+
+        self.push_str(unsafe {
+            core::str::from_utf8_unchecked(&buf[..valid_len])
+        });
+        Ok(valid_len)
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+///Appends UTF-8 input, consuming whole buffer on every `write`.
+///
+///Character split between writes is carried over, while invalid UTF-8 is rejected with `ErrorKind::InvalidData`.
+///`flush` fails with `ErrorKind::InvalidData` while there is pending incomplete character, unless appender is lossy.
+impl<const N: usize> io::Write for crate::Utf8Appender<'_, N> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.push_chunk(buf) {
+            Ok(()) => Ok(buf.len()),
+            Err(error) => Err(io::Error::new(io::ErrorKind::InvalidData, error)),
+        }
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        match self.pending().is_empty() || self.is_lossy() {
+            true => Ok(()),
+            false => Err(io::Error::new(io::ErrorKind::InvalidData, self.pending_error())),
+        }
+    }
+}
+
+impl<const N: usize> ToSocketAddrs for SmallString<N> {
     type Iter = vec::IntoIter<SocketAddr>;
     #[inline(always)]
     fn to_socket_addrs(&self) -> io::Result<vec::IntoIter<SocketAddr>> {
@@ -16,16 +180,148 @@ impl ToSocketAddrs for String {
     }
 }
 
-impl AsRef<OsStr> for String {
+impl<const N: usize> AsRef<OsStr> for SmallString<N> {
     #[inline(always)]
     fn as_ref(&self) -> &OsStr {
         self.as_str().as_ref()
     }
 }
 
-impl AsRef<Path> for String {
+impl<const N: usize> AsRef<Path> for SmallString<N> {
     #[inline(always)]
     fn as_ref(&self) -> &Path {
         Path::new(self.as_str())
     }
 }
+
+impl<const N: usize> core::convert::TryFrom<std::ffi::CString> for SmallString<N> {
+    type Error = std::ffi::IntoStringError;
+
+    #[inline]
+    ///Converts C string into `String`, returning original value within error if it is not valid UTF-8.
+    fn try_from(text: std::ffi::CString) -> Result<Self, std::ffi::IntoStringError> {
+        match text.to_str() {
+            Ok(utf8) => Ok(Self::new_str(utf8)),
+            Err(_) => text.into_string().map(Self::from_std),
+        }
+    }
+}
+
+impl<const N: usize> core::convert::TryFrom<OsString> for SmallString<N> {
+    type Error = OsString;
+
+    #[inline]
+    ///Converts OS string into `String`, returning original value if it is not valid Unicode.
+    fn try_from(text: OsString) -> Result<Self, OsString> {
+        match text.to_str() {
+            Some(utf8) => Ok(Self::new_str(utf8)),
+            None => Err(text),
+        }
+    }
+}
+
+//Conversions from `&String` are provided by std via `AsRef<OsStr>`.
+impl<const N: usize> From<SmallString<N>> for OsString {
+    #[inline(always)]
+    fn from(s: SmallString<N>) -> OsString {
+        OsString::from(s.into_std())
+    }
+}
+
+impl<const N: usize> From<SmallString<N>> for PathBuf {
+    #[inline(always)]
+    fn from(s: SmallString<N>) -> PathBuf {
+        PathBuf::from(s.into_std())
+    }
+}
+
+macro_rules! impl_os_eq {
+    ($($typ:ty => $base:ident),*) => {
+        $(
+            impl<const N: usize> PartialEq<$typ> for SmallString<N> {
+                #[inline(always)]
+                fn eq(&self, other: &$typ) -> bool {
+                    PartialEq::eq($base::new(self.as_str()), AsRef::<$base>::as_ref(other))
+                }
+            }
+
+            impl<const N: usize> PartialEq<SmallString<N>> for $typ {
+                #[inline(always)]
+                fn eq(&self, other: &SmallString<N>) -> bool {
+                    PartialEq::eq(AsRef::<$base>::as_ref(self), $base::new(other.as_str()))
+                }
+            }
+        )*
+    };
+}
+
+//Path has no `PartialEq<str>`, hence comparison is performed component-wise as for `Path` itself.
+impl_os_eq!(OsStr => OsStr, &OsStr => OsStr, OsString => OsStr, Path => Path, &Path => Path, PathBuf => Path);
+
+//Carries message of error converted from `String`, as `String` itself is not an error.
+struct StringError<const N: usize>(SmallString<N>);
+
+impl<const N: usize> core::fmt::Debug for StringError<N> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.0, fmt)
+    }
+}
+
+impl<const N: usize> core::fmt::Display for StringError<N> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, fmt)
+    }
+}
+
+impl<const N: usize> std::error::Error for StringError<N> {
+}
+
+impl<const N: usize> From<SmallString<N>> for std::boxed::Box<dyn std::error::Error + Send + Sync> {
+    #[inline]
+    fn from(s: SmallString<N>) -> Self {
+        std::boxed::Box::new(StringError(s))
+    }
+}
+
+impl<const N: usize> From<SmallString<N>> for std::boxed::Box<dyn std::error::Error> {
+    #[inline]
+    fn from(s: SmallString<N>) -> Self {
+        std::boxed::Box::new(StringError(s))
+    }
+}
+
+impl std::error::Error for crate::Utf32Error {
+}
+
+#[cfg(feature = "base64")]
+impl std::error::Error for crate::Base64DecodeError {
+}
+
+impl std::error::Error for crate::TryReserveError {
+}
+
+impl std::error::Error for crate::BoundaryError {
+}
+
+impl std::error::Error for crate::RangeError {
+}
+
+impl std::error::Error for crate::FromUtf8Error {
+}
+
+impl std::error::Error for crate::FromUtf16Error {
+}
+
+impl std::error::Error for crate::AsciiError {
+}
+
+impl std::error::Error for crate::IncompleteUtf8Error {
+}
+
+impl std::error::Error for crate::EmptyError {
+}
+
+impl std::error::Error for crate::CapacityError {
+}
@@ -16,6 +16,16 @@ pub trait StrExt {
     ///Returns the uppercase equivalent of this string slice, as a new `String`.
     fn to_uppercase(&self) -> String;
 
+    ///Appends the lowercase equivalent of this string slice to `out`.
+    ///
+    ///`out` is not cleared, allowing to re-use its capacity across calls by clearing it explicitly.
+    fn to_lowercase_into(&self, out: &mut String);
+
+    ///Appends the uppercase equivalent of this string slice to `out`.
+    ///
+    ///`out` is not cleared, allowing to re-use its capacity across calls by clearing it explicitly.
+    fn to_uppercase_into(&self, out: &mut String);
+
     ///Returns a copy of this string where each character is mapped to its
     ///ASCII upper case equivalent.
     ///
@@ -27,6 +37,118 @@ pub trait StrExt {
     ///
     ///ASCII letters ‘A’ to ‘Z’ are mapped to ‘a’ to ‘z’, but non-ASCII letters are unchanged.
     fn to_ascii_lowercase(&self) -> String;
+
+    ///Returns ASCII copy of this string, where each non-ASCII character is replaced with `'?'`.
+    ///
+    ///No transliteration is performed, so output always has as many bytes as input has characters.
+    fn to_ascii_lossy(&self) -> String;
+
+    ///Returns ASCII copy of this string, where each non-ASCII character is replaced with `replacement`.
+    ///
+    ///No transliteration is performed, so output always has as many bytes as input has characters.
+    ///
+    ///## Panics
+    ///
+    ///If `replacement` is not ASCII.
+    fn to_ascii_lossy_with(&self, replacement: char) -> String;
+
+    ///Returns whether string consists only of printable ASCII characters, i.e. from `' '` to `'~'`.
+    ///
+    ///Control characters, including tab and newline, are not printable. Empty string is printable.
+    fn is_ascii_printable(&self) -> bool;
+
+    ///Returns whether string starts with `prefix`, ignoring ASCII case.
+    ///
+    ///Non-ASCII characters are compared exactly.
+    fn starts_with_ignore_ascii_case(&self, prefix: &str) -> bool;
+
+    ///Returns whether string ends with `suffix`, ignoring ASCII case.
+    ///
+    ///Non-ASCII characters are compared exactly.
+    fn ends_with_ignore_ascii_case(&self, suffix: &str) -> bool;
+
+    ///Returns whether string contains `needle`, ignoring ASCII case.
+    ///
+    ///Non-ASCII characters are compared exactly.
+    fn contains_ignore_ascii_case(&self, needle: &str) -> bool;
+
+    ///Shortens string to at most `max_chars` characters by replacing its middle with `marker`.
+    ///
+    ///Budget remaining after `marker` is split between start and end of the string, with start
+    ///getting extra character when budget is odd. If string already fits, returns its copy.
+    ///
+    ///If `marker` itself does not fit, returns `marker` truncated to `max_chars` characters.
+    fn elide_middle(&self, max_chars: usize, marker: &str) -> String;
+
+    ///Returns copy of this string padded with `'0'` on the left up to `width` characters.
+    ///
+    ///Zeros are inserted after leading `'+'` or `'-'`, if any. Content is not required to be numeric.
+    fn zfill(&self, width: usize) -> String;
+
+    ///Returns iterator over byte offsets of every character boundary, from `0` to `len()` inclusive.
+    ///
+    ///Boundaries are found by inspecting UTF-8 lead bytes, without decoding characters.
+    fn char_boundaries(&self) -> crate::CharBoundaries<'_>;
+
+    ///Returns key for case and accent insensitive sorting of this string.
+    ///
+    ///Key is lowercase equivalent of the string, with Latin letters from Latin-1 Supplement and
+    ///Latin Extended-A folded to their base letters (e.g. `ä` to `a`, `ß` to `ss`) and combining
+    ///diacritical marks removed. Hence comparing keys byte-wise orders strings ignoring case and accents.
+    ///Strings with equal keys should be ordered by original string in order to make sorting
+    ///deterministic. Applying function to key returns the same key.
+    ///
+    ///This is not collation: letters of other scripts are only lowercased and ordered by code point.
+    fn to_sort_key(&self) -> String;
+
+    ///Returns number of words, separated by Unicode whitespace.
+    fn word_count(&self) -> usize;
+
+    ///Returns number of occurrences of `ch`.
+    fn char_frequency(&self, ch: char) -> usize;
+
+    ///Returns longest word, separated by Unicode whitespace, measured in characters.
+    ///
+    ///If multiple words have the same length, the first one is returned.
+    ///Returns `None` if there are no words.
+    fn longest_word(&self) -> Option<&str>;
+
+    ///Returns copy of this string, suitable for embedding into single line of text.
+    ///
+    ///`\n`, `\r` and `\t` are replaced with their backslash escapes, while other control characters
+    ///are replaced with `\u{NNNN}` escape. Everything else, including backslash, quotes and non-ASCII
+    ///characters, is left as it is.
+    ///
+    ///Unlike `escape_debug`, quotes and non-printable characters are not escaped.
+    fn escape_for_single_line(&self) -> String;
+
+    ///Returns copy of the remainder after removing `prefix`, or `None` if string does not start with it.
+    fn strip_prefix_owned(&self, prefix: &str) -> Option<String>;
+
+    ///Returns copy of the remainder after removing `suffix`, or `None` if string does not end with it.
+    fn strip_suffix_owned(&self, suffix: &str) -> Option<String>;
+
+    ///Returns copy of the remainder after removing `prefix` character, or `None` if string does not start with it.
+    fn strip_prefix_char_owned(&self, prefix: char) -> Option<String>;
+
+    ///Returns copy of the remainder after removing `suffix` character, or `None` if string does not end with it.
+    fn strip_suffix_char_owned(&self, suffix: char) -> Option<String>;
+
+    ///Returns byte index of `n`-th (starting from 0) occurrence of `pat`, or `None` if there are fewer occurrences.
+    fn find_nth(&self, pat: char, n: usize) -> Option<usize>;
+
+    ///Splits string on the last occurrence of `pat`, returning copies of both halves without separator.
+    ///
+    ///Returns `None` if `pat` does not occur in string.
+    fn rsplit_once_owned(&self, pat: char) -> Option<(String, String)>;
+
+    #[cfg(feature = "base64")]
+    ///Decodes base64 encoded string.
+    ///
+    ///Both standard and URL-safe alphabets are accepted, while padding is optional.
+    ///
+    ///In case of invalid input, returns error with offset of the first invalid character.
+    fn base64_decode(&self) -> Result<alloc::vec::Vec<u8>, crate::Base64DecodeError>;
 }
 
 impl StrExt for str {
@@ -59,11 +181,30 @@ impl StrExt for str {
 
     #[inline]
     fn to_lowercase(&self) -> String {
-        let mut res = String::with_capacity(self.len());
+        let mut res = String::new();
+        self.to_lowercase_into(&mut res);
+        res
+    }
+
+    #[inline]
+    fn to_uppercase(&self) -> String {
+        let mut res = String::new();
+        self.to_uppercase_into(&mut res);
+        res
+    }
+
+    fn to_lowercase_into(&self, out: &mut String) {
+        if self.is_ascii() {
+            let start = out.len();
+            out.push_str(self);
+            out[start..].make_ascii_lowercase();
+            return;
+        }
 
+        out.reserve(self.len());
         for ch in self.chars() {
             for ch in ch.to_lowercase() {
-                res.push(ch)
+                out.push(ch)
             }
         }
 
@@ -77,22 +218,48 @@ impl StrExt for str {
         //        }
         //    }
         //}
+    }
 
-        res
+    fn to_uppercase_into(&self, out: &mut String) {
+        if self.is_ascii() {
+            let start = out.len();
+            out.push_str(self);
+            out[start..].make_ascii_uppercase();
+            return;
+        }
+
+        out.reserve(self.len());
+        for ch in self.chars() {
+            for ch in ch.to_uppercase() {
+                out.push(ch)
+            }
+        }
     }
 
     #[inline]
-    fn to_uppercase(&self) -> String {
+    fn to_ascii_lossy(&self) -> String {
+        self.to_ascii_lossy_with('?')
+    }
+
+    fn to_ascii_lossy_with(&self, replacement: char) -> String {
+        assert!(replacement.is_ascii(), "replacement {:?} is not ASCII", replacement);
+
+        //Output is never longer than input, hence it is allocated once.
         let mut res = String::with_capacity(self.len());
         for ch in self.chars() {
-            for ch in ch.to_uppercase() {
-                res.push(ch)
+            match ch.is_ascii() {
+                true => res.push(ch),
+                false => res.push(replacement),
             }
         }
-
         res
     }
 
+    #[inline]
+    fn is_ascii_printable(&self) -> bool {
+        self.bytes().all(|byte| matches!(byte, b' '..=b'~'))
+    }
+
     #[inline]
     fn to_ascii_uppercase(&self) -> String {
         let mut res = String::new_str(self);
@@ -107,4 +274,193 @@ impl StrExt for str {
         res
     }
 
+    #[inline]
+    fn starts_with_ignore_ascii_case(&self, prefix: &str) -> bool {
+        match self.as_bytes().get(..prefix.len()) {
+            Some(start) => start.eq_ignore_ascii_case(prefix.as_bytes()),
+            None => false,
+        }
+    }
+
+    #[inline]
+    fn ends_with_ignore_ascii_case(&self, suffix: &str) -> bool {
+        match self.len().checked_sub(suffix.len()) {
+            Some(start) => self.as_bytes()[start..].eq_ignore_ascii_case(suffix.as_bytes()),
+            None => false,
+        }
+    }
+
+    #[inline]
+    fn contains_ignore_ascii_case(&self, needle: &str) -> bool {
+        if needle.is_empty() {
+            return true;
+        }
+
+        //Needle starts with non-continuation byte, hence matches always start at char boundary
+        self.as_bytes().windows(needle.len()).any(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+    }
+
+    fn elide_middle(&self, max_chars: usize, marker: &str) -> String {
+        #[inline(always)]
+        fn char_idx(text: &str, nth: usize) -> usize {
+            text.char_indices().nth(nth).map(|(idx, _)| idx).unwrap_or(text.len())
+        }
+
+        let len = self.chars().count();
+        if len <= max_chars {
+            return String::new_str(self);
+        }
+
+        let marker_len = marker.chars().count();
+        if marker_len >= max_chars {
+            return String::new_str(&marker[..char_idx(marker, max_chars)]);
+        }
+
+        let budget = max_chars - marker_len;
+        let head = &self[..char_idx(self, budget - budget / 2)];
+        let tail = &self[char_idx(self, len - budget / 2)..];
+
+        let mut res = String::with_capacity(head.len() + marker.len() + tail.len());
+        res.push_str(head);
+        res.push_str(marker);
+        res.push_str(tail);
+        res
+    }
+
+    #[inline]
+    fn zfill(&self, width: usize) -> String {
+        let mut res = String::with_capacity(width.max(self.len()));
+        res.push_str(self);
+        res.zfill_in_place(width);
+        res
+    }
+
+    #[inline(always)]
+    fn char_boundaries(&self) -> crate::CharBoundaries<'_> {
+        crate::CharBoundaries::new(self)
+    }
+
+    fn to_sort_key(&self) -> String {
+        let mut res = String::with_capacity(self.len());
+        for ch in self.chars().flat_map(char::to_lowercase) {
+            match fold_latin_diacritic(ch) {
+                Some(base) => res.push_str(base),
+                None => res.push(ch),
+            }
+        }
+        res
+    }
+
+    #[inline]
+    fn word_count(&self) -> usize {
+        self.split_whitespace().count()
+    }
+
+    #[inline]
+    fn char_frequency(&self, ch: char) -> usize {
+        self.matches(ch).count()
+    }
+
+    fn longest_word(&self) -> Option<&str> {
+        let mut res: Option<(&str, usize)> = None;
+        for word in self.split_whitespace() {
+            let len = word.chars().count();
+            match res {
+                Some((_, longest)) if longest >= len => (),
+                _ => res = Some((word, len)),
+            }
+        }
+        res.map(|(word, _)| word)
+    }
+
+    fn escape_for_single_line(&self) -> String {
+        let start = match self.find(char::is_control) {
+            Some(start) => start,
+            None => return String::new_str(self),
+        };
+
+        let mut res = String::with_capacity(self.len() + 1);
+        res.push_str(&self[..start]);
+        for ch in self[start..].chars() {
+            match ch {
+                '\n' => res.push_str("\\n"),
+                '\r' => res.push_str("\\r"),
+                '\t' => res.push_str("\\t"),
+                ch if ch.is_control() => {
+                    let _ = core::fmt::Write::write_fmt(&mut res, format_args!("\\u{{{:04x}}}", ch as u32));
+                },
+                ch => res.push(ch),
+            }
+        }
+        res
+    }
+
+    #[inline]
+    fn strip_prefix_owned(&self, prefix: &str) -> Option<String> {
+        self.strip_prefix(prefix).map(String::new_str)
+    }
+
+    #[inline]
+    fn strip_suffix_owned(&self, suffix: &str) -> Option<String> {
+        self.strip_suffix(suffix).map(String::new_str)
+    }
+
+    #[inline]
+    fn strip_prefix_char_owned(&self, prefix: char) -> Option<String> {
+        self.strip_prefix(prefix).map(String::new_str)
+    }
+
+    #[inline]
+    fn strip_suffix_char_owned(&self, suffix: char) -> Option<String> {
+        self.strip_suffix(suffix).map(String::new_str)
+    }
+
+    #[inline]
+    fn find_nth(&self, pat: char, n: usize) -> Option<usize> {
+        self.match_indices(pat).nth(n).map(|(idx, _)| idx)
+    }
+
+    #[inline]
+    fn rsplit_once_owned(&self, pat: char) -> Option<(String, String)> {
+        self.rsplit_once(pat).map(|(left, right)| (String::new_str(left), String::new_str(right)))
+    }
+
+    #[cfg(feature = "base64")]
+    #[inline(always)]
+    fn base64_decode(&self) -> Result<alloc::vec::Vec<u8>, crate::Base64DecodeError> {
+        crate::base64::decode(self)
+    }
+}
+
+//Returns base letters of lowercase Latin letter with diacritic, or empty string for combining mark.
+fn fold_latin_diacritic(ch: char) -> Option<&'static str> {
+    let base = match ch {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ĥ' | 'ħ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ĳ' => "ij",
+        'ĵ' => "j",
+        'ķ' | 'ĸ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' | 'ŉ' | 'ŋ' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'œ' => "oe",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' | 'ſ' => "s",
+        'ß' => "ss",
+        'ţ' | 'ť' | 'ŧ' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        '\u{300}'..='\u{36f}' => "",
+        _ => return None,
+    };
+    Some(base)
 }
@@ -0,0 +1,135 @@
+//! String newtypes with statically known invariants.
+
+use core::{fmt, ops};
+use core::convert::TryFrom;
+
+use crate::{String, EmptyError};
+
+///`String` that is guaranteed to be non-empty.
+///
+///Mutation is limited to appending, which cannot violate the invariant.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptyString(String);
+
+impl NonEmptyString {
+    #[inline]
+    ///Wraps `string`, returning error if it is empty.
+    pub fn new(string: String) -> Result<Self, EmptyError> {
+        match string.is_empty() {
+            true => Err(EmptyError),
+            false => Ok(Self(string)),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns string slice.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    #[inline(always)]
+    ///Returns reference to underlying `String`.
+    pub fn as_string(&self) -> &String {
+        &self.0
+    }
+
+    #[inline(always)]
+    ///Unwraps underlying `String`.
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    #[inline(always)]
+    ///Appends given `ch` to the end of string.
+    pub fn push(&mut self, ch: char) {
+        self.0.push(ch)
+    }
+
+    #[inline(always)]
+    ///Appends given `string` to the end of string.
+    pub fn push_str(&mut self, string: &str) {
+        self.0.push_str(string)
+    }
+}
+
+impl ops::Deref for NonEmptyString {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.0.as_str()
+    }
+}
+
+impl AsRef<str> for NonEmptyString {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl TryFrom<String> for NonEmptyString {
+    type Error = EmptyError;
+
+    #[inline(always)]
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        Self::new(string)
+    }
+}
+
+impl TryFrom<&str> for NonEmptyString {
+    type Error = EmptyError;
+
+    #[inline]
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        match string.is_empty() {
+            true => Err(EmptyError),
+            false => Ok(Self(String::new_str(string))),
+        }
+    }
+}
+
+impl core::str::FromStr for NonEmptyString {
+    type Err = EmptyError;
+
+    #[inline(always)]
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::try_from(string)
+    }
+}
+
+impl From<NonEmptyString> for String {
+    #[inline(always)]
+    fn from(string: NonEmptyString) -> Self {
+        string.0
+    }
+}
+
+impl PartialEq<str> for NonEmptyString {
+    #[inline(always)]
+    fn eq(&self, other: &str) -> bool {
+        self.0.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for NonEmptyString {
+    #[inline(always)]
+    fn eq(&self, other: &&str) -> bool {
+        self.0.as_str() == *other
+    }
+}
+
+impl fmt::Debug for NonEmptyString {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for NonEmptyString {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
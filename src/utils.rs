@@ -25,11 +25,12 @@ pub trait MiniStr {
 impl MiniStr for minivec::MiniVec<u8> {
     #[inline(always)]
     fn as_str(&self) -> &str {
-        #[cfg(not(debug_assertions))]
+        //Full validation is O(n) on every call, hence it is opt-in
+        #[cfg(not(feature = "paranoid"))]
         unsafe {
             core::str::from_utf8_unchecked(self.as_slice())
         }
-        #[cfg(debug_assertions)]
+        #[cfg(feature = "paranoid")]
         {
             core::str::from_utf8(self.as_slice()).expect("To contain UTF-8")
         }
@@ -0,0 +1,364 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+//Allocations above this size fail while `FAIL` is set
+const FAIL_SIZE: usize = 1024;
+static FAIL: AtomicBool = AtomicBool::new(false);
+//Tests toggle global state, hence they must not run concurrently
+static LOCK: Mutex<()> = Mutex::new(());
+
+struct FailingAlloc;
+
+unsafe impl GlobalAlloc for FailingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if FAIL.load(Ordering::Relaxed) && layout.size() > FAIL_SIZE {
+            return core::ptr::null_mut();
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if FAIL.load(Ordering::Relaxed) && new_size > FAIL_SIZE {
+            return core::ptr::null_mut();
+        }
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: FailingAlloc = FailingAlloc;
+
+fn with_failing_alloc<R, F: FnOnce() -> R>(cb: F) -> R {
+    let _guard = LOCK.lock().unwrap_or_else(|error| error.into_inner());
+    FAIL.store(true, Ordering::Relaxed);
+    let result = cb();
+    FAIL.store(false, Ordering::Relaxed);
+    result
+}
+
+#[test]
+pub fn should_try_clone_sso_string() {
+    let stroka = stroka::String::new_str("lolka");
+    let result = with_failing_alloc(|| stroka.try_clone()).expect("to clone");
+    assert!(!result.is_alloc());
+    assert_eq!(result, stroka);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_try_clone_heap_string() {
+    let stroka = stroka::String::new_str(&"1単語8".repeat(10));
+    let result = stroka.try_clone().expect("to clone");
+    assert!(result.is_alloc());
+    assert_eq!(result, stroka);
+    assert_ne!(result.as_ptr(), stroka.as_ptr());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_fail_try_clone_on_alloc_failure() {
+    let stroka = stroka::String::new_str(&"1単語8".repeat(FAIL_SIZE));
+    let error = with_failing_alloc(|| stroka.try_clone()).expect_err("to fail clone");
+    assert!(matches!(error, stroka::TryReserveError::AllocError { .. }));
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_try_reserve_within_capacity() {
+    let mut stroka = stroka::String::new_str("lolka");
+    with_failing_alloc(|| stroka.try_reserve(1)).expect("to reserve");
+    with_failing_alloc(|| stroka.try_reserve_exact(stroka.capacity() - stroka.len())).expect("to reserve");
+    assert!(!stroka.is_alloc());
+
+    let mut stroka = stroka::String::with_capacity(FAIL_SIZE * 2);
+    let ptr = stroka.as_ptr();
+    with_failing_alloc(|| stroka.try_reserve(FAIL_SIZE)).expect("to reserve");
+    assert_eq!(stroka.as_ptr(), ptr);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_try_reserve_onto_heap() {
+    let mut stroka = stroka::String::new_str("lolka");
+    stroka.try_reserve(32).expect("to reserve");
+    assert!(stroka.is_alloc());
+    assert!(stroka.capacity() >= 37);
+    assert_eq!(stroka, "lolka");
+
+    let mut stroka = stroka::String::new_str("lolka");
+    stroka.try_reserve_exact(32).expect("to reserve");
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka.capacity(), 37);
+    assert_eq!(stroka, "lolka");
+
+    stroka.try_reserve_exact(64).expect("to reserve");
+    assert_eq!(stroka.capacity(), 69);
+    assert_eq!(stroka, "lolka");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_fail_try_reserve_on_overflow() {
+    let mut stroka = stroka::String::new_str("lolka");
+    assert_eq!(stroka.try_reserve(usize::MAX), Err(stroka::TryReserveError::CapacityOverflow));
+    assert_eq!(stroka.try_reserve_exact(usize::MAX), Err(stroka::TryReserveError::CapacityOverflow));
+    assert!(!stroka.is_alloc());
+
+    let mut stroka = stroka::String::new_str(&"1単語8".repeat(10));
+    assert_eq!(stroka.try_reserve(usize::MAX), Err(stroka::TryReserveError::CapacityOverflow));
+    assert_eq!(stroka.try_reserve_exact(usize::MAX), Err(stroka::TryReserveError::CapacityOverflow));
+    assert_eq!(stroka, "1単語8".repeat(10));
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_fail_try_reserve_on_alloc_failure() {
+    let mut stroka = stroka::String::new_str("lolka");
+    let error = with_failing_alloc(|| stroka.try_reserve(FAIL_SIZE)).expect_err("to fail reserve");
+    assert!(matches!(error, stroka::TryReserveError::AllocError { .. }));
+    assert!(!stroka.is_alloc());
+    assert_eq!(stroka, "lolka");
+
+    let mut stroka = stroka::String::new_str(&"1単語8".repeat(10));
+    let error = with_failing_alloc(|| stroka.try_reserve_exact(FAIL_SIZE)).expect_err("to fail reserve");
+    assert!(matches!(error, stroka::TryReserveError::AllocError { .. }));
+    assert_eq!(stroka, "1単語8".repeat(10));
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+pub fn should_panic_on_sso_reserve_overflow() {
+    let mut stroka = stroka::String::new();
+    stroka.reserve(usize::MAX);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+#[should_panic(expected = "capacity overflow")]
+pub fn should_panic_on_heap_reserve_overflow() {
+    let mut stroka = stroka::String::new_str(&"1単語8".repeat(10));
+    stroka.reserve(usize::MAX);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+pub fn should_panic_on_sso_reserve_exact_overflow() {
+    let mut stroka = stroka::String::new_str("lolka");
+    stroka.reserve_exact(usize::MAX);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+#[should_panic(expected = "capacity overflow")]
+pub fn should_panic_on_heap_reserve_exact_overflow() {
+    let mut stroka = stroka::String::new_str(&"1単語8".repeat(10));
+    stroka.reserve_exact(usize::MAX);
+}
+
+#[test]
+pub fn should_write_into_sso_spare_capacity() {
+    let mut stroka = stroka::String::new_str("lol");
+    let capacity = stroka.capacity();
+    let spare = stroka.spare_capacity_mut();
+    assert_eq!(spare.len(), capacity - 3);
+    for (idx, byte) in b"ka".iter().enumerate() {
+        spare[idx].write(*byte);
+    }
+    unsafe {
+        stroka.set_len(5);
+    }
+    assert_eq!(stroka, "lolka");
+    assert!(!stroka.is_alloc());
+
+    let text = "l".repeat(capacity - 5);
+    let spare = stroka.spare_capacity_mut();
+    for (idx, byte) in text.bytes().enumerate() {
+        spare[idx].write(byte);
+    }
+    unsafe {
+        stroka.set_len(capacity);
+    }
+    assert!(stroka.spare_capacity_mut().is_empty());
+    assert_eq!(stroka.as_str(), format!("lolka{}", text));
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_write_into_heap_spare_capacity() {
+    let mut stroka = stroka::String::with_capacity(64);
+    assert!(stroka.is_alloc());
+    stroka.push_str("単語:");
+    let spare = stroka.spare_capacity_mut();
+    assert_eq!(spare.len(), 64 - "単語:".len());
+    let text = "lolka lol lolid by loli";
+    for (idx, byte) in text.bytes().enumerate() {
+        spare[idx].write(byte);
+    }
+    unsafe {
+        stroka.set_len("単語:".len() + text.len());
+    }
+    assert_eq!(stroka, "単語:lolka lol lolid by loli");
+    assert_eq!(stroka.capacity(), 64);
+}
+
+fn write_bytes(spare: &mut [std::mem::MaybeUninit<u8>], bytes: &[u8]) -> usize {
+    for (idx, byte) in bytes.iter().enumerate() {
+        spare[idx].write(*byte);
+    }
+    bytes.len()
+}
+
+#[test]
+pub fn should_create_from_raw_writer_inline() {
+    let capacity = stroka::String::INLINE_CAPACITY;
+    let text = "l".repeat(capacity);
+    let stroka = unsafe {
+        stroka::String::from_raw_writer(capacity, |spare| {
+            assert_eq!(spare.len(), capacity);
+            write_bytes(spare, text.as_bytes())
+        })
+    };
+    assert_eq!(stroka, text);
+    assert!(!stroka.is_alloc());
+
+    let stroka = unsafe {
+        stroka::String::from_raw_writer(0, |spare| write_bytes(spare, "単語".as_bytes()))
+    };
+    assert_eq!(stroka, "単語");
+    assert!(!stroka.is_alloc());
+
+    let stroka = unsafe {
+        stroka::String::from_raw_writer(4, |_| 0)
+    };
+    assert_eq!(stroka, "");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_create_from_raw_writer_on_heap() {
+    const TEXT: &str = "lolka lol lolid by loli 単語";
+    let stroka = unsafe {
+        stroka::String::from_raw_writer(64, |spare| {
+            assert_eq!(spare.len(), 64);
+            write_bytes(spare, TEXT.as_bytes())
+        })
+    };
+    assert_eq!(stroka, TEXT);
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka.capacity(), 64);
+}
+
+#[test]
+#[should_panic(expected = "writer returned length 15 exceeding buffer of 14 bytes")]
+pub fn should_panic_on_raw_writer_length_overflow() {
+    let _ = unsafe {
+        stroka::String::from_raw_writer(14, |_| 15)
+    };
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "writer produced invalid UTF-8")]
+pub fn should_panic_on_raw_writer_invalid_utf8() {
+    let _ = unsafe {
+        stroka::String::from_raw_writer(4, |spare| write_bytes(spare, &[0xff]))
+    };
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_round_trip_heap_raw_parts() {
+    const TEXT: &str = "lolka lol lolid by loli";
+    let mut stroka = stroka::String::with_capacity(64);
+    stroka.push_str(TEXT);
+    let expected_ptr = stroka.as_ptr();
+
+    let (ptr, len, capacity, is_heap) = stroka.into_raw_parts();
+    assert!(is_heap);
+    assert_eq!(ptr as *const u8, expected_ptr);
+    assert_eq!(len, TEXT.len());
+    assert_eq!(capacity, 64);
+
+    let mut stroka = unsafe {
+        stroka::String::from_heap_parts(ptr, len, capacity)
+    };
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka, TEXT);
+    assert_eq!(stroka.capacity(), 64);
+    stroka.push_str("!");
+    assert_eq!(stroka.as_str(), format!("{}!", TEXT));
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_copy_inline_string_into_raw_parts() {
+    let stroka = stroka::String::new_str("lolka");
+    assert!(!stroka.is_alloc());
+
+    let (ptr, len, capacity, is_heap) = stroka.into_raw_parts();
+    assert!(!is_heap);
+    assert_eq!(len, 5);
+    assert!(capacity >= len);
+
+    let stroka = unsafe {
+        stroka::String::from_heap_parts(ptr, len, capacity)
+    };
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka, "lolka");
+
+    let (ptr, len, capacity, _) = stroka::String::new().into_raw_parts();
+    let stroka = unsafe {
+        stroka::String::from_heap_parts(ptr, len, capacity)
+    };
+    assert_eq!(stroka, "");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_take_heap_string_with_capacity() {
+    let mut stroka = stroka::String::with_capacity(64);
+    stroka.push_str("lolka lol lolid by loli");
+    let ptr = stroka.as_ptr();
+
+    let taken = stroka.take();
+    assert!(taken.is_alloc());
+    assert_eq!(taken.as_ptr(), ptr);
+    assert_eq!(taken.capacity(), 64);
+    assert_eq!(taken, "lolka lol lolid by loli");
+
+    assert!(stroka.is_empty());
+    assert!(!stroka.is_alloc());
+    stroka.push_str("lolka");
+    assert_eq!(stroka, "lolka");
+
+    let taken = stroka.take();
+    assert!(!taken.is_alloc());
+    assert_eq!(taken, "lolka");
+    assert_eq!(stroka, "");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_swap_strings_keeping_storage() {
+    let mut heap = stroka::String::with_capacity(64);
+    heap.push_str("lolka lol lolid by loli");
+    let ptr = heap.as_ptr();
+    let mut inline = stroka::String::new_str("lolka");
+
+    heap.swap(&mut inline);
+    assert_eq!(heap, "lolka");
+    assert!(!heap.is_alloc());
+    assert_eq!(inline, "lolka lol lolid by loli");
+    assert!(inline.is_alloc());
+    assert_eq!(inline.as_ptr(), ptr);
+    assert_eq!(inline.capacity(), 64);
+
+    inline.swap(&mut heap);
+    assert_eq!(heap.as_ptr(), ptr);
+    assert_eq!(inline, "lolka");
+}
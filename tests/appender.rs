@@ -0,0 +1,64 @@
+use stroka::Utf8Appender;
+
+const VALID: [&str; 4] = ["1単語8", "❤😀a😀", "ßü", "lolka"];
+const INVALID: [&[u8]; 6] = [b"lol\xFFka", b"\xE5\x8D", b"\xE5\x8Da", b"\xF0\x9F\x98", b"a\xC0\x80b", b"\xED\xA0\x80"];
+
+fn append(chunks: &[&[u8]], lossy: bool) -> Result<stroka::String, ()> {
+    let mut stroka = stroka::String::new();
+    let mut appender = match lossy {
+        true => Utf8Appender::lossy(&mut stroka),
+        false => Utf8Appender::new(&mut stroka),
+    };
+    for chunk in chunks {
+        appender.push_chunk(chunk).map_err(|_| ())?;
+    }
+    appender.finish().map_err(|_| ())?;
+    Ok(stroka)
+}
+
+fn for_each_split<F: FnMut(&[&[u8]])>(bytes: &[u8], mut cb: F) {
+    for first in 0..=bytes.len() {
+        cb(&[&bytes[..first], &bytes[first..]]);
+        for second in first..=bytes.len() {
+            cb(&[&bytes[..first], &bytes[first..second], &bytes[second..]]);
+        }
+    }
+}
+
+#[test]
+pub fn should_append_valid_chunks() {
+    for text in VALID.iter() {
+        for_each_split(text.as_bytes(), |chunks| {
+            assert_eq!(append(chunks, false).expect("to be valid"), *text, "chunks={:?}", chunks);
+            assert_eq!(append(chunks, true).expect("to be valid"), *text, "chunks={:?}", chunks);
+        });
+    }
+}
+
+#[test]
+pub fn should_append_invalid_chunks() {
+    for bytes in INVALID.iter() {
+        let expected = std::string::String::from_utf8_lossy(bytes);
+        for_each_split(bytes, |chunks| {
+            assert!(append(chunks, false).is_err(), "chunks={:?}", chunks);
+            assert_eq!(append(chunks, true).expect("lossy"), expected, "chunks={:?}", chunks);
+        });
+    }
+}
+
+#[test]
+pub fn should_report_incomplete_char() {
+    let mut stroka = stroka::String::new_str("1");
+    let mut appender = Utf8Appender::new(&mut stroka);
+    appender.push_chunk(b"\xE5").expect("to be incomplete");
+    appender.push_chunk(b"\x8D").expect("to be incomplete");
+    assert_eq!(appender.pending(), b"\xE5\x8D");
+    let error = appender.finish().expect_err("to be incomplete");
+    assert_eq!(error.as_bytes(), b"\xE5\x8D");
+    assert_eq!(stroka, "1");
+
+    let mut appender = Utf8Appender::new(&mut stroka);
+    let error = appender.push_chunk(b"lol\xFFka").expect_err("to be invalid");
+    assert_eq!(error.valid_up_to(), 3);
+    assert_eq!(stroka, "1lol");
+}
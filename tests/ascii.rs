@@ -0,0 +1,216 @@
+#[test]
+pub fn should_mutate_ascii_bytes() {
+    let mut stroka = stroka::String::new_str("lolka-1");
+    {
+        let mut bytes = stroka.as_ascii_bytes_mut().expect("to be ASCII");
+        bytes[0] = b'L';
+        *bytes.last_mut().unwrap() = b'9';
+    }
+    assert_eq!(stroka, "Lolka-9");
+}
+
+#[test]
+pub fn should_mask_non_ascii_write() {
+    let mut stroka = stroka::String::new_str("lolka");
+    {
+        let mut bytes = stroka.as_ascii_bytes_mut().expect("to be ASCII");
+        bytes[0] = 0xE8;
+    }
+    assert_eq!(stroka, "holka");
+    assert!(stroka.is_ascii());
+}
+
+#[test]
+pub fn should_keep_utf8_when_ascii_guard_is_leaked() {
+    let mut stroka = stroka::String::new_str("lolka");
+    {
+        let mut bytes = stroka.as_ascii_bytes_mut().expect("to be ASCII");
+        bytes[0] = 0xFF;
+        core::mem::forget(bytes);
+    }
+    assert!(core::str::from_utf8(stroka.as_bytes()).is_ok());
+    assert_eq!(stroka, "");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_keep_utf8_when_heap_ascii_guard_is_leaked() {
+    let mut stroka = stroka::String::new_str("lolka lol lolid by loli");
+    assert!(stroka.is_alloc());
+    {
+        let mut bytes = stroka.as_ascii_bytes_mut().expect("to be ASCII");
+        bytes[22] = 0xFF;
+        core::mem::forget(bytes);
+    }
+    assert!(core::str::from_utf8(stroka.as_bytes()).is_ok());
+    assert_eq!(stroka, "");
+
+    let mut stroka = stroka::String::new_str("lolka lol lolid by loli");
+    stroka.as_ascii_bytes_mut().expect("to be ASCII")[22] = 0xE8;
+    assert_eq!(stroka, "lolka lol lolid by lolh");
+}
+
+#[test]
+pub fn should_not_give_ascii_bytes_of_non_ascii() {
+    let mut stroka = stroka::String::new_str("単語");
+    assert!(stroka.as_ascii_bytes_mut().is_none());
+
+    let mut stroka = stroka::String::new();
+    assert_eq!(stroka.as_ascii_bytes_mut().unwrap().len(), 0);
+}
+
+#[test]
+pub fn should_convert_from_ascii() {
+    let stroka = stroka::String::from_ascii(b"GET /lolka").expect("to be ASCII");
+    assert_eq!(stroka, "GET /lolka");
+    assert!(!stroka.is_alloc());
+
+    let error = stroka::String::from_ascii(b"GET /\x80lolka").expect_err("to be not ASCII");
+    assert_eq!(error.index(), 5);
+    assert_eq!(error.byte(), 0x80);
+
+    let error = stroka::String::from_ascii("単".as_bytes()).expect_err("to be not ASCII");
+    assert_eq!(error.index(), 0);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_from_ascii_outside_sso_cap() {
+    let stroka = stroka::String::from_ascii(b"GET /lolka/lol/lolid HTTP/1.1").expect("to be ASCII");
+    assert_eq!(stroka, "GET /lolka/lol/lolid HTTP/1.1");
+    assert!(stroka.is_alloc());
+}
+
+#[test]
+pub fn should_push_ascii() {
+    let mut stroka = stroka::String::new();
+    for byte in b"lolka".iter() {
+        stroka.push_ascii(*byte).expect("to be ASCII");
+    }
+    let error = stroka.push_ascii(0xFF).expect_err("to be not ASCII");
+    assert_eq!((error.index(), error.byte()), (0, 0xFF));
+    assert_eq!(stroka, "lolka");
+
+    stroka.push_ascii_str(b" lol").expect("to be ASCII");
+    let error = stroka.push_ascii_str(b"ka\xC3\xA9").expect_err("to be not ASCII");
+    assert_eq!(error.index(), 2);
+    assert_eq!(stroka, "lolka lol");
+}
+
+#[test]
+pub fn should_compare_with_bytes() {
+    let stroka = stroka::String::new_str("GET");
+    assert!(stroka.eq_bytes(b"GET"));
+    assert!(!stroka.eq_bytes(b"POST"));
+    assert!(!stroka.eq_bytes(b"GET\xFF"));
+
+    let get: &[u8] = b"GET";
+    let lower_get: &[u8] = b"get";
+    let invalid: &[u8] = b"\xFF";
+    assert!(stroka == *get);
+    assert!(stroka == get);
+    assert!(*get == stroka);
+    assert!(get == stroka);
+    assert!(stroka != lower_get);
+    assert!(invalid != stroka);
+
+    let stroka = stroka::String::new_str("単語");
+    assert!(stroka == "単語".as_bytes());
+    assert!(!stroka.eq_bytes(b"\xE5\x8D"));
+}
+
+#[test]
+pub fn should_make_ascii_titlecase() {
+    let mut stroka = stroka::String::new_str("usd  eUR\tgbp");
+    stroka.make_ascii_titlecase();
+    assert_eq!(stroka, "Usd  Eur\tGbp");
+    assert!(!stroka.is_alloc());
+
+    stroka.make_ascii_titlecase();
+    assert_eq!(stroka, "Usd  Eur\tGbp");
+
+    let mut stroka = stroka::String::new_str("  aAPL ");
+    stroka.make_ascii_titlecase();
+    assert_eq!(stroka, "  Aapl ");
+
+    let mut stroka = stroka::String::new();
+    stroka.make_ascii_titlecase();
+    assert_eq!(stroka, "");
+}
+
+#[test]
+pub fn should_make_ascii_titlecase_leaving_non_ascii() {
+    let mut stroka = stroka::String::new_str("élan ÜBER");
+    stroka.make_ascii_titlecase();
+    assert_eq!(stroka, "éLan ÜBer");
+
+    let mut stroka = stroka::String::new_str("単語 42nd");
+    stroka.make_ascii_titlecase();
+    assert_eq!(stroka, "単語 42Nd");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_make_ascii_titlecase_outside_sso_cap() {
+    let mut stroka = stroka::String::new_str("new york stock EXCHANGE ❤ nasdaq");
+    assert!(stroka.is_alloc());
+    stroka.make_ascii_titlecase();
+    assert_eq!(stroka, "New York Stock Exchange ❤ Nasdaq");
+}
+
+#[test]
+pub fn should_convert_to_ascii_lossy() {
+    use stroka::StrExt;
+
+    const CASES: [(&str, &str); 5] = [
+        ("", ""),
+        ("lolka", "lolka"),
+        ("単語", "??"),
+        ("café ❤ 1", "caf? ? 1"),
+        ("\u{10348}\t\n", "?\t\n"),
+    ];
+
+    for (text, expected) in CASES.iter() {
+        let res = text.to_ascii_lossy();
+        assert_eq!(res, *expected);
+        assert!(res.is_ascii());
+        assert_eq!(res.len(), text.chars().count());
+        assert_eq!(text.to_ascii_lossy_with('?'), res);
+    }
+
+    assert_eq!("d単mo.lolka".to_ascii_lossy_with('-'), "d-mo.lolka");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_to_ascii_lossy_outside_sso_cap() {
+    use stroka::StrExt;
+
+    const TEXT: &str = "lolka 単語 lolid by loli";
+    let res = TEXT.to_ascii_lossy_with('_');
+    assert_eq!(res, "lolka __ lolid by loli");
+    assert!(res.is_ascii());
+    assert_eq!(res.capacity(), TEXT.len());
+}
+
+#[test]
+#[should_panic(expected = "replacement '単' is not ASCII")]
+pub fn should_panic_on_non_ascii_replacement() {
+    use stroka::StrExt;
+
+    "lolka".to_ascii_lossy_with('単');
+}
+
+#[test]
+pub fn should_check_ascii_printable() {
+    use stroka::StrExt;
+
+    assert!("".is_ascii_printable());
+    assert!(" lolka~!".is_ascii_printable());
+    assert!(stroka::String::new_str("id=1").is_ascii_printable());
+    assert!(!"lol\tka".is_ascii_printable());
+    assert!(!"lolka\n".is_ascii_printable());
+    assert!(!"\x7f".is_ascii_printable());
+    assert!(!"café".is_ascii_printable());
+    assert!("café".to_ascii_lossy().is_ascii_printable());
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "base64")]
+
+use stroka::{StrExt, Base64Config};
+
+const RFC4648_VECTORS: [(&str, &str); 7] = [
+    ("", ""),
+    ("f", "Zg=="),
+    ("fo", "Zm8="),
+    ("foo", "Zm9v"),
+    ("foob", "Zm9vYg=="),
+    ("fooba", "Zm9vYmE="),
+    ("foobar", "Zm9vYmFy"),
+];
+
+#[test]
+pub fn should_encode_rfc4648_vectors() {
+    for (input, expected) in RFC4648_VECTORS {
+        let encoded = stroka::String::from_base64_encode(input.as_bytes(), Base64Config::STANDARD);
+        assert_eq!(encoded, expected);
+        assert_eq!(encoded.len(), Base64Config::STANDARD.encoded_len(input.len()));
+
+        let encoded = stroka::String::from_base64_encode(input.as_bytes(), Base64Config::STANDARD_NO_PAD);
+        assert_eq!(encoded, expected.trim_end_matches('='));
+    }
+}
+
+#[test]
+pub fn should_decode_rfc4648_vectors() {
+    for (expected, input) in RFC4648_VECTORS {
+        assert_eq!(StrExt::base64_decode(input).expect("to decode"), expected.as_bytes());
+        assert_eq!(StrExt::base64_decode(input.trim_end_matches('=')).expect("to decode"), expected.as_bytes());
+    }
+}
+
+#[test]
+pub fn should_encode_url_safe() {
+    const INPUT: [u8; 2] = [0xfb, 0xff];
+    assert_eq!(stroka::String::from_base64_encode(&INPUT, Base64Config::STANDARD), "+/8=");
+    assert_eq!(stroka::String::from_base64_encode(&INPUT, Base64Config::URL_SAFE), "-_8=");
+    assert_eq!(stroka::String::from_base64_encode(&INPUT, Base64Config::URL_SAFE_NO_PAD), "-_8");
+    assert_eq!(StrExt::base64_decode("-_8").expect("to decode"), INPUT);
+}
+
+#[test]
+pub fn should_encode_inline_for_short_input() {
+    let encoded = stroka::String::from_base64_encode(b"0123456789", Base64Config::STANDARD_NO_PAD);
+    assert_eq!(encoded, "MDEyMzQ1Njc4OQ");
+    assert!(!encoded.is_alloc());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_round_trip() {
+    let input = (0..=255u8).collect::<Vec<_>>();
+    for len in 0..input.len() {
+        for cfg in [Base64Config::STANDARD, Base64Config::STANDARD_NO_PAD, Base64Config::URL_SAFE, Base64Config::URL_SAFE_NO_PAD] {
+            let encoded = stroka::String::from_base64_encode(&input[..len], cfg);
+            assert_eq!(encoded.len(), cfg.encoded_len(len));
+            assert_eq!(encoded.base64_decode().expect("to decode"), &input[..len]);
+        }
+    }
+}
+
+#[test]
+pub fn should_fail_to_decode_invalid_input() {
+    assert_eq!(StrExt::base64_decode("Zm9v!mFy").expect_err("to fail").offset(), 4);
+    assert_eq!(StrExt::base64_decode("Zm=vYmFy").expect_err("to fail").offset(), 2);
+    assert_eq!(StrExt::base64_decode("Zm9vY").expect_err("to fail").offset(), 4);
+    assert_eq!(StrExt::base64_decode("Zm8==").expect_err("to fail").offset(), 3);
+    assert_eq!(StrExt::base64_decode("Zg===").expect_err("to fail").offset(), 2);
+}
@@ -0,0 +1,43 @@
+use core::cmp::Ordering;
+
+const CHARS: [char; 5] = ['a', 'Z', 'é', '単', '\u{10348}'];
+
+#[test]
+pub fn should_compare_with_char() {
+    for ch in CHARS.iter().copied() {
+        let stroka = stroka::String::from(ch);
+        assert!(stroka.eq_char(ch));
+        assert_eq!(stroka, ch);
+
+        let mut longer = stroka.clone();
+        longer.push(ch);
+        assert!(!longer.eq_char(ch));
+        assert_ne!(longer, ch);
+
+        let mut trailing = stroka.clone();
+        trailing.push_str("x");
+        assert_ne!(trailing, ch);
+    }
+
+    let empty = stroka::String::new();
+    assert!(!empty.eq_char('\0'));
+    assert_ne!(empty, 'a');
+    assert_eq!(stroka::String::from('\0'), '\0');
+    assert_ne!(stroka::String::new_str("e\u{301}"), 'é');
+    assert_ne!(stroka::String::new_str("語"), '単');
+}
+
+#[test]
+pub fn should_order_against_char_as_str() {
+    let mut buf = [0u8; 4];
+    for ch in CHARS.iter().copied() {
+        for text in ["", "a", "aa", "Z", "é", "単語", "単", "\u{10348}"].iter() {
+            let stroka = stroka::String::new_str(text);
+            let expected = text.cmp(&&*ch.encode_utf8(&mut buf));
+            assert_eq!(stroka.partial_cmp(&ch), Some(expected), "{:?} vs {:?}", text, ch);
+            assert_eq!(stroka == ch, expected == Ordering::Equal);
+        }
+    }
+    assert!(stroka::String::new() < 'a');
+    assert!(stroka::String::new_str("ab") < 'b');
+}
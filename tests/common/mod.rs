@@ -0,0 +1,30 @@
+//! Helpers shared by integration tests.
+
+const SSO_CAP: usize = stroka::String::INLINE_CAPACITY;
+//Includes combining characters, which are ordered by code point, not by their visual form.
+pub const ALPHABET: [char; 12] = ['a', 'b', 'Z', '0', ' ', 'ß', 'é', 'e', '\u{301}', '単', '❤', '\u{10348}'];
+
+//Deterministic xorshift to generate strings without extra dependencies
+pub struct Rng(pub u64);
+
+impl Rng {
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    //Generates string of `ALPHABET` characters, which fits inline capacity only with `sso-only` feature.
+    pub fn string(&mut self) -> std::string::String {
+        let max_len = if cfg!(feature = "sso-only") { SSO_CAP } else { SSO_CAP * 2 };
+        let mut res = std::string::String::new();
+        loop {
+            let ch = ALPHABET[self.next() as usize % ALPHABET.len()];
+            if res.len() + ch.len_utf8() > max_len || (self.next() & 7) == 0 {
+                break res;
+            }
+            res.push(ch);
+        }
+    }
+}
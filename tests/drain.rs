@@ -0,0 +1,214 @@
+#[test]
+pub fn should_drain_empty_range() {
+    let mut stroka = stroka::String::new_str("1単語8");
+    assert_eq!(stroka.drain(1..1).count(), 0);
+    assert_eq!(stroka.drain(stroka.len()..).count(), 0);
+    assert_eq!(stroka, "1単語8");
+}
+
+#[test]
+pub fn should_drop_partially_consumed_sso_drain() {
+    let mut stroka = stroka::String::new_str("1単語8");
+    assert!(!stroka.is_alloc());
+
+    let mut drain = stroka.drain(1..);
+    assert_eq!(drain.next(), Some('単'));
+    assert_eq!(drain.as_str(), "語8");
+    drop(drain);
+
+    assert_eq!(stroka, "1");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_drop_partially_consumed_heap_drain() {
+    let mut stroka = stroka::String::new_str("1単語8 long enough string");
+    assert!(stroka.is_alloc());
+
+    let mut drain = stroka.drain(1..8);
+    assert_eq!(drain.next_back(), Some('8'));
+    assert_eq!(drain.next(), Some('単'));
+    drop(drain);
+
+    assert_eq!(stroka, "1 long enough string");
+}
+
+#[test]
+#[should_panic]
+pub fn should_panic_on_drain_within_char() {
+    let mut stroka = stroka::String::new_str("1単語8");
+    stroka.drain(2..);
+}
+
+#[test]
+pub fn should_remove_matches() {
+    let mut stroka = stroka::String::new_str("aaa");
+    stroka.remove_matches("aa");
+    assert_eq!(stroka, "a");
+
+    let mut stroka = stroka::String::new_str("\rlol\r\nka\r");
+    stroka.remove_matches("\r");
+    assert_eq!(stroka, "lol\nka");
+    stroka.remove_matches("");
+    assert_eq!(stroka, "lol\nka");
+    stroka.remove_matches("lol\nka");
+    assert_eq!(stroka, "");
+
+    let mut stroka = stroka::String::new_str("単語1単語8");
+    stroka.remove_matches("単語");
+    assert_eq!(stroka, "18");
+
+    let mut stroka = stroka::String::new_str("単1語単");
+    stroka.remove_matches("単");
+    assert_eq!(stroka, "1語");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_remove_matches_from_heap_string() {
+    let mut stroka = stroka::String::new_str("lol, ka, lolid, by, loli, ");
+    let ptr = stroka.as_ptr();
+    let capacity = stroka.capacity();
+    stroka.remove_matches(", ");
+    assert_eq!(stroka, "lolkalolidbyloli");
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka.as_ptr(), ptr);
+    assert_eq!(stroka.capacity(), capacity);
+}
+
+#[test]
+pub fn should_return_match_ranges() {
+    let stroka = stroka::String::new_str("aaaa");
+    assert_eq!(stroka.match_ranges("aa"), [0..2, 2..4]);
+    assert_eq!(stroka.count_matches("aa"), 2);
+    let ranges = stroka.match_ranges("aaa");
+    assert_eq!(ranges.len(), 1);
+    assert_eq!(ranges[0], 0..3);
+    assert_eq!(stroka.count_matches("aaa"), 1);
+    assert_eq!(stroka.match_ranges_char('a'), [0..1, 1..2, 2..3, 3..4]);
+
+    let stroka = stroka::String::new_str("単lol単");
+    assert_eq!(stroka.match_ranges("単"), [0..3, 6..9]);
+    assert_eq!(stroka.match_ranges_char('単'), [0..3, 6..9]);
+    assert_eq!(stroka.count_matches("単"), 2);
+    let ranges = stroka.match_ranges("単lol単");
+    assert_eq!(ranges.len(), 1);
+    assert_eq!(ranges[0], 0..9);
+
+    assert!(stroka.match_ranges("語").is_empty());
+    assert!(stroka.match_ranges_char('x').is_empty());
+    assert_eq!(stroka.count_matches("語"), 0);
+    assert!(stroka::String::new().match_ranges("a").is_empty());
+}
+
+#[test]
+pub fn should_edit_by_match_ranges() {
+    let mut stroka = stroka::String::new_str("a-b-c");
+    for range in stroka.match_ranges("-").into_iter().rev() {
+        stroka.replace_range(range, "::");
+    }
+    assert_eq!(stroka, "a::b::c");
+    assert_eq!(stroka.count_matches("::"), 2);
+}
+
+#[test]
+pub fn should_squeeze_char_runs() {
+    let mut stroka = stroka::String::new_str("///a//b/");
+    assert_eq!(stroka.squeeze('/'), 3);
+    assert_eq!(stroka, "/a/b/");
+    assert_eq!(stroka.squeeze('/'), 0);
+    assert_eq!(stroka, "/a/b/");
+
+    let mut stroka = stroka::String::new_str("a..b...");
+    assert_eq!(stroka.squeeze('.'), 3);
+    assert_eq!(stroka, "a.b.");
+
+    let mut stroka = stroka::String::new_str("------");
+    assert_eq!(stroka.squeeze('-'), 5);
+    assert_eq!(stroka, "-");
+
+    let mut stroka = stroka::String::new_str("aa--bb");
+    assert_eq!(stroka.squeeze('-'), 1);
+    assert_eq!(stroka, "aa-bb");
+
+    let mut stroka = stroka::String::new_str("❤❤単❤");
+    assert_eq!(stroka.squeeze('❤'), 1);
+    assert_eq!(stroka, "❤単❤");
+
+    let mut stroka = stroka::String::new();
+    assert_eq!(stroka.squeeze('/'), 0);
+    assert_eq!(stroka, "");
+}
+
+#[test]
+pub fn should_squeeze_all_char_runs() {
+    let mut stroka = stroka::String::new_str("aabbbca");
+    assert_eq!(stroka.squeeze_all(), 3);
+    assert_eq!(stroka, "abca");
+
+    let mut stroka = stroka::String::new_str("単単語語");
+    assert_eq!(stroka.squeeze_all(), 2);
+    assert_eq!(stroka, "単語");
+
+    let mut stroka = stroka::String::new_str("////");
+    assert_eq!(stroka.squeeze_all(), 3);
+    assert_eq!(stroka, "/");
+
+    let mut stroka = stroka::String::new_str("abc");
+    assert_eq!(stroka.squeeze_all(), 0);
+    assert_eq!(stroka, "abc");
+    assert_eq!(stroka::String::new().squeeze_all(), 0);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_squeeze_heap_string() {
+    let mut stroka = stroka::String::new_str("//usr///local//lib//stroka/");
+    let ptr = stroka.as_ptr();
+    assert_eq!(stroka.squeeze('/'), 5);
+    assert_eq!(stroka, "/usr/local/lib/stroka/");
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka.as_ptr(), ptr);
+
+    let mut stroka = stroka::String::new_str("lolkaa  lol   lolid by loli");
+    assert_eq!(stroka.squeeze_all(), 4);
+    assert_eq!(stroka, "lolka lol lolid by loli");
+}
+
+#[test]
+pub fn should_prepend() {
+    let mut stroka = stroka::String::new();
+    stroka.prepend("");
+    assert_eq!(stroka, "");
+    stroka.prepend("語8");
+    assert_eq!(stroka, "語8");
+    stroka.prepend_char('単');
+    stroka.prepend_char('1');
+    assert_eq!(stroka, "1単語8");
+
+    let mut stroka = stroka::String::new_str("lolka");
+    stroka.prepend("123456789");
+    assert_eq!(stroka.len(), stroka.capacity());
+    assert!(!stroka.is_alloc());
+    assert_eq!(stroka, "123456789lolka");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_prepend_onto_heap() {
+    const CASES: [(&str, &str); 4] = [("lolka", "1234567890"), ("", "lolka lol lolid by loli"), ("lolka lol lolid by loli", "単"), ("1単語8", "単語")];
+    for (text, prefix) in CASES.iter() {
+        let mut expected = stroka::String::new_str(text);
+        expected.insert_str(0, prefix);
+
+        let mut stroka = stroka::String::new_str(text);
+        stroka.prepend(prefix);
+        assert_eq!(stroka, expected);
+        assert_eq!(stroka.is_alloc(), expected.is_alloc());
+    }
+
+    let mut stroka = stroka::String::new_str("1234567890123");
+    stroka.prepend_char('単');
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka, "単1234567890123");
+}
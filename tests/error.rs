@@ -0,0 +1,36 @@
+#![cfg(feature = "std")]
+
+use std::error::Error;
+
+fn fail(id: u32) -> Result<(), Box<dyn Error>> {
+    Err(stroka::format!("req {}: 単語", id).into())
+}
+
+fn fail_sync(id: u32) -> Result<(), Box<dyn Error + Send + Sync>> {
+    Err(stroka::format!("req {}: 単語", id).into())
+}
+
+#[test]
+pub fn should_convert_into_boxed_error() {
+    let error = fail(1).expect_err("to fail");
+    assert_eq!(error.to_string(), "req 1: 単語");
+    assert_eq!(format!("{:?}", error), "\"req 1: 単語\"");
+    assert!(error.source().is_none());
+    assert!(error.downcast_ref::<std::io::Error>().is_none());
+
+    let error = fail_sync(2).expect_err("to fail");
+    assert_eq!(error.to_string(), "req 2: 単語");
+    let error = std::thread::spawn(move || error.to_string()).join().expect("to join");
+    assert_eq!(error, "req 2: 単語");
+
+    let error: Box<dyn Error> = stroka::String::new().into();
+    assert_eq!(error.to_string(), "");
+}
+
+#[test]
+pub fn should_box_error_into_io_error() {
+    let error = std::io::Error::other(stroka::String::new_str("lolka"));
+    assert_eq!(error.to_string(), "lolka");
+    let inner = error.into_inner().expect("to have inner error");
+    assert_eq!(inner.to_string(), "lolka");
+}
@@ -0,0 +1,129 @@
+use core::convert::TryFrom;
+use core::ffi::CStr;
+
+#[test]
+pub fn should_convert_from_c_str() {
+    let text = CStr::from_bytes_with_nul(b"\0").expect("valid C string");
+    let stroka = stroka::String::try_from(text).expect("valid UTF-8");
+    assert_eq!(stroka, "");
+
+    let text = CStr::from_bytes_with_nul("1単語8 lolka\0".as_bytes()).expect("valid C string");
+    assert_eq!(text.to_bytes().len(), stroka::String::INLINE_CAPACITY);
+    let stroka = stroka::String::try_from(text).expect("valid UTF-8");
+    assert_eq!(stroka, "1単語8 lolka");
+    assert!(!stroka.is_alloc());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_from_c_str_outside_sso_cap() {
+    let text = CStr::from_bytes_with_nul("1単語8 lolka!\0".as_bytes()).expect("valid C string");
+    let stroka = stroka::String::try_from(text).expect("valid UTF-8");
+    assert_eq!(stroka, "1単語8 lolka!");
+    assert!(stroka.is_alloc());
+}
+
+#[test]
+pub fn should_fail_from_invalid_utf8_c_str() {
+    let text = CStr::from_bytes_with_nul(b"lol\xe5\x8dka\0").expect("valid C string");
+    let error = stroka::String::try_from(text).expect_err("invalid UTF-8");
+    assert_eq!(error.valid_up_to(), 3);
+}
+
+#[cfg(feature = "std")]
+#[test]
+pub fn should_convert_to_c_string() {
+    use std::ffi::CString;
+
+    let stroka = stroka::String::new_str("1単語8");
+    let c_string = stroka.to_c_string().expect("no NUL");
+    assert_eq!(c_string.as_bytes_with_nul(), "1単語8\0".as_bytes());
+
+    let c_string = stroka::String::new().to_c_string().expect("no NUL");
+    assert_eq!(c_string.as_bytes_with_nul(), b"\0");
+
+    let error = stroka::String::new_str("lol\0ka").to_c_string().expect_err("to reject NUL");
+    assert_eq!(error.nul_position(), 3);
+    let error = stroka::String::new_str("\0").to_c_string().expect_err("to reject NUL");
+    assert_eq!(error.nul_position(), 0);
+
+    let stroka = stroka::String::try_from(CString::new("1単語8 lolka").expect("no NUL")).expect("valid UTF-8");
+    assert_eq!(stroka, "1単語8 lolka");
+    assert!(!stroka.is_alloc());
+
+    let error = stroka::String::try_from(CString::new(&b"lol\xffka"[..]).expect("no NUL")).expect_err("invalid UTF-8");
+    assert_eq!(error.utf8_error().valid_up_to(), 3);
+    assert_eq!(error.into_cstring().as_bytes(), b"lol\xffka");
+}
+
+#[cfg(all(feature = "std", not(feature = "sso-only")))]
+#[test]
+pub fn should_round_trip_long_c_string() {
+    const TEXT: &str = "lolka lol lolid by loli 単語";
+    let c_string = stroka::String::new_str(TEXT).to_c_string().expect("no NUL");
+    let stroka = stroka::String::try_from(c_string).expect("valid UTF-8");
+    assert_eq!(stroka, TEXT);
+    assert!(stroka.is_alloc());
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+pub fn should_round_trip_through_view() {
+    use stroka::ffi::StrView;
+
+    for text in ["", "lolka", "1単語8 lolka"].iter() {
+        let stroka = stroka::String::new_str(text);
+        let view = stroka.as_view();
+        assert_eq!(view.len, text.len());
+        assert_eq!(view.ptr, stroka.as_ptr());
+
+        let copy = unsafe { stroka::String::from_view_copy(view) }.expect("valid UTF-8");
+        assert_eq!(copy, *text);
+        assert!(!copy.is_alloc());
+    }
+
+    let empty = StrView {
+        ptr: core::ptr::null(),
+        len: 0,
+    };
+    let copy = unsafe { stroka::String::from_view_copy(empty) }.expect("empty");
+    assert_eq!(copy, "");
+}
+
+#[cfg(all(feature = "ffi", not(feature = "sso-only")))]
+#[test]
+pub fn should_round_trip_heap_string_through_view() {
+    const TEXT: &str = "lolka lol lolid by loli 単語";
+    let stroka = stroka::String::new_str(TEXT);
+    let view = stroka.as_view();
+    assert_eq!(view.ptr, stroka.as_ptr());
+
+    let copy = unsafe { stroka::String::from_view_copy(view) }.expect("valid UTF-8");
+    assert_eq!(copy, TEXT);
+    assert!(copy.is_alloc());
+    assert_ne!(copy.as_ptr(), view.ptr);
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+pub fn should_fail_view_copy_of_invalid_utf8() {
+    use stroka::ffi::StrView;
+
+    const BYTES: &[u8] = b"lol\xffka";
+    let view = StrView {
+        ptr: BYTES.as_ptr(),
+        len: BYTES.len(),
+    };
+    let error = unsafe { stroka::String::from_view_copy(view) }.expect_err("invalid UTF-8");
+    assert_eq!(error.utf8_error().valid_up_to(), 3);
+    assert_eq!(error.as_bytes(), BYTES);
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+pub fn should_have_c_layout_of_view() {
+    use stroka::ffi::StrView;
+
+    assert_eq!(core::mem::size_of::<StrView>(), core::mem::size_of::<usize>() * 2);
+    assert_eq!(core::mem::align_of::<StrView>(), core::mem::align_of::<usize>());
+}
@@ -0,0 +1,50 @@
+macro_rules! assert_fmt_eq {
+    ($text:expr, $($fmt:literal),+) => {{
+        let std = std::string::String::from($text);
+        let stroka = stroka::String::new_str($text);
+        $(
+            assert_eq!(format!($fmt, stroka), format!($fmt, std), "format {:?} of {:?}", $fmt, $text);
+        )+
+    }};
+}
+
+fn check_formatting(text: &str) {
+    assert_fmt_eq!(text, "{}", "{:?}", "{:10}", "{:<10}", "{:>10}", "{:^10}", "{:*^11}", "{:.3}", "{:>10.3}", "{:-<8.2}", "{:.0}", "{:1}", "{:>40}", "{:>10?}");
+}
+
+#[test]
+pub fn should_display_inline_string_as_std() {
+    let stroka = stroka::String::new_str("hi");
+    assert!(!stroka.is_alloc());
+    assert_eq!(format!("{}", stroka), "hi");
+    assert_eq!(stroka.to_string(), "hi");
+
+    check_formatting("");
+    check_formatting("hi");
+    check_formatting("lolka");
+    check_formatting("単語");
+}
+
+#[test]
+pub fn should_display_special_characters_as_std() {
+    let stroka = stroka::String::new_str("\"a\\b\n");
+    assert_eq!(format!("{}", stroka), "\"a\\b\n");
+    assert_eq!(format!("{:?}", stroka), r#""\"a\\b\n""#);
+
+    check_formatting("\"quoted\"");
+    check_formatting("back\\slash");
+    check_formatting("new\nline\t");
+    check_formatting("\"単\\語\n\"");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_display_heap_string_as_std() {
+    let stroka = stroka::String::new_str("lolka lol lolid by loli");
+    assert!(stroka.is_alloc());
+    assert_eq!(format!("{}", stroka), "lolka lol lolid by loli");
+
+    check_formatting("lolka lol lolid by loli");
+    check_formatting("単語単語単語単語単語単語");
+    check_formatting("\"heap\\string\nwith escapes\"");
+}
@@ -0,0 +1,86 @@
+use core::fmt;
+
+struct Hearts(usize);
+
+impl fmt::Display for Hearts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for _ in 0..self.0 {
+            f.write_str("❤")?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+pub fn should_push_formatted_fluently() {
+    let id = 42u32;
+    let mut stroka = stroka::String::new();
+    stroka.append("id=").push_display(&id).append(" ").push_debug("ok").push_display(&Hearts(1));
+    assert_eq!(stroka, format!("id={} {:?}{}", id, "ok", Hearts(1)));
+    assert!(!stroka.is_alloc());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_push_formatted_across_sso_boundary() {
+    let mut stroka = stroka::String::new();
+    stroka.append("<").push_display(&Hearts(3)).append(">").push_display(&Hearts(2)).push_debug(&Some('単'));
+    assert_eq!(stroka, format!("<{}>{}{:?}", Hearts(3), Hearts(2), Some('単')));
+    assert!(stroka.is_alloc());
+}
+
+#[test]
+pub fn should_convert_from_arguments() {
+    let stroka = stroka::String::from(format_args!("lolka"));
+    assert_eq!(stroka, "lolka");
+    assert!(!stroka.is_alloc());
+
+    let stroka = stroka::String::from(format_args!("{}={:?}", "id", 42));
+    assert_eq!(stroka, "id=42");
+    assert!(!stroka.is_alloc());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_from_arguments_outside_sso_cap() {
+    let stroka = stroka::String::from(format_args!("lolka lol lolid by loli"));
+    assert_eq!(stroka, "lolka lol lolid by loli");
+    assert_eq!(stroka.capacity(), stroka.len());
+
+    let stroka = stroka::String::from(format_args!("{}: {}", "lolka lol lolid", Hearts(3)));
+    assert_eq!(stroka, "lolka lol lolid: ❤❤❤");
+    assert_eq!(stroka.capacity(), stroka.len());
+}
+
+#[test]
+pub fn should_create_from_display_iter() {
+    let stroka = stroka::String::from_display_iter(core::iter::empty::<u32>(), ", ");
+    assert_eq!(stroka, "");
+    assert!(!stroka.is_alloc());
+
+    let stroka = stroka::String::from_display_iter(Some(42), ", ");
+    assert_eq!(stroka, "42");
+
+    let stroka = stroka::String::from_display_iter(1..=3, ", ");
+    assert_eq!(stroka, "1, 2, 3");
+    assert!(!stroka.is_alloc());
+
+    let stroka = stroka::String::from_display_iter([1.5f32, -0.25].iter(), ";");
+    assert_eq!(stroka, "1.5;-0.25");
+
+    let stroka = stroka::String::from_display_iter(["a", "b"].iter(), "");
+    assert_eq!(stroka, "ab");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_create_from_display_iter_across_sso_boundary() {
+    let items = [Hearts(1), Hearts(6), Hearts(2)];
+    let stroka = stroka::String::from_display_iter(items.iter(), " | ");
+    assert_eq!(stroka, format!("{} | {} | {}", Hearts(1), Hearts(6), Hearts(2)));
+    assert!(stroka.is_alloc());
+
+    let stroka = stroka::String::from_display_iter(0..100u32, ",");
+    let expected = (0..100u32).map(|num| num.to_string()).collect::<Vec<_>>().join(",");
+    assert_eq!(stroka, expected);
+}
@@ -0,0 +1,27 @@
+extern crate alloc;
+
+use alloc::string::ToString;
+
+macro_rules! assert_from {
+    ($($value:expr),+) => {
+        $(
+            assert_eq!(stroka::String::from($value), $value.to_string());
+        )+
+    }
+}
+
+#[test]
+pub fn should_convert_from_small_primitives() {
+    assert_from!(0u8, u8::MAX, i8::MIN, i8::MAX, u16::MAX, i16::MIN, u32::MAX, i32::MIN, i32::MAX);
+    assert_from!(true, false, 0.5f32, -1.25f64, 0usize, 42isize);
+
+    assert!(!stroka::String::from(u32::MAX).is_alloc());
+    assert!(!stroka::String::from(i32::MIN).is_alloc());
+    assert!(!stroka::String::from(true).is_alloc());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_from_large_primitives() {
+    assert_from!(u64::MAX, i64::MIN, u128::MAX, i128::MIN, usize::MAX, isize::MIN, f32::MAX, f64::MIN_POSITIVE);
+}
@@ -0,0 +1,113 @@
+#![cfg(all(feature = "tuning", not(feature = "sso-only")))]
+
+const BIG: &str = " lol lolid by loli";
+
+type Promotion = (&'static str, fn() -> stroka::String);
+
+//Every way to move inline string onto heap
+fn promotions() -> [Promotion; 10] {
+    [
+        ("push", || {
+            let mut stroka = stroka::String::new_str(&BIG[..stroka::String::INLINE_CAPACITY]);
+            stroka.push('1');
+            stroka
+        }),
+        ("push_str", || {
+            let mut stroka = stroka::String::new_str("lolka");
+            stroka.push_str(BIG);
+            stroka
+        }),
+        ("insert_str", || {
+            let mut stroka = stroka::String::new_str("lolka");
+            stroka.insert_str(1, BIG);
+            stroka
+        }),
+        ("prepend", || {
+            let mut stroka = stroka::String::new_str("lolka");
+            stroka.prepend(BIG);
+            stroka
+        }),
+        ("replace_range", || {
+            let mut stroka = stroka::String::new_str("lolka");
+            stroka.replace_range(1..2, BIG);
+            stroka
+        }),
+        ("reserve", || {
+            let mut stroka = stroka::String::new();
+            stroka.reserve(stroka::String::INLINE_CAPACITY + 1);
+            stroka
+        }),
+        ("reserve_exact", || {
+            let mut stroka = stroka::String::new();
+            stroka.reserve_exact(stroka::String::INLINE_CAPACITY + 1);
+            stroka
+        }),
+        ("try_reserve", || {
+            let mut stroka = stroka::String::new();
+            stroka.try_reserve(stroka::String::INLINE_CAPACITY + 1).expect("to reserve");
+            stroka
+        }),
+        ("try_reserve_exact", || {
+            let mut stroka = stroka::String::new();
+            stroka.try_reserve_exact(stroka::String::INLINE_CAPACITY + 1).expect("to reserve");
+            stroka
+        }),
+        ("extend", || {
+            let mut stroka = stroka::String::new_str("lolka");
+            stroka.extend(BIG.chars());
+            stroka
+        }),
+    ]
+}
+
+//Knob is process-global, hence everything is checked within single test.
+#[test]
+pub fn should_honor_min_heap_capacity_on_promotion() {
+    assert_eq!(stroka::growth::min_heap_capacity(), 0);
+    stroka::growth::reset_growth_stats();
+
+    for (name, promote) in promotions().iter() {
+        let stroka = promote();
+        assert!(stroka.is_alloc(), "{}", name);
+        assert!(stroka.capacity() < 64, "{}", name);
+    }
+    let stats = stroka::String::growth_stats();
+    assert_eq!(stats.promotions, promotions().len());
+    assert!(stats.promoted_capacity < 64 * promotions().len());
+
+    stroka::growth::set_min_heap_capacity(64);
+    assert_eq!(stroka::growth::min_heap_capacity(), 64);
+    stroka::growth::reset_growth_stats();
+    assert_eq!(stroka::String::growth_stats(), stroka::growth::GrowthStats::default());
+
+    for (name, promote) in promotions().iter() {
+        let stroka = promote();
+        assert!(stroka.is_alloc(), "{}", name);
+        assert!(stroka.capacity() >= 64, "{}", name);
+    }
+    let stats = stroka::growth::growth_stats();
+    assert_eq!(stats.promotions, promotions().len());
+    assert!(stats.promoted_capacity >= 64 * promotions().len());
+
+    let mut stroka = stroka::String::new_str("lolka");
+    stroka.push_str(BIG);
+    assert_eq!(stroka, "lolka lol lolid by loli");
+    let mut stroka = stroka::String::new_str("lolka");
+    stroka.prepend(BIG);
+    assert_eq!(stroka, " lol lolid by lolilolka");
+
+    //Growth of heap storage is not promotion
+    stroka::growth::reset_growth_stats();
+    stroka.push_str(&BIG.repeat(10));
+    assert_eq!(stroka::growth::growth_stats().promotions, 0);
+
+    //Requests above minimum are unaffected
+    let mut stroka = stroka::String::new();
+    stroka.reserve(128);
+    assert!(stroka.capacity() >= 128);
+
+    stroka::growth::set_min_heap_capacity(0);
+    let mut stroka = stroka::String::new_str("lolka");
+    stroka.push_str(BIG);
+    assert!(stroka.capacity() < 64);
+}
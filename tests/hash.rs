@@ -0,0 +1,118 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+use std::ops::Bound;
+
+mod common;
+use common::Rng;
+
+fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+pub fn should_hash_consistently_with_str() {
+    let mut rng = Rng(0x5EED);
+    for _ in 0..1000 {
+        let text = rng.string();
+        let stroka = stroka::String::new_str(&text);
+
+        assert_eq!(hash_of(&stroka), hash_of(text.as_str()));
+        assert_eq!(hash_of(&stroka), hash_of(Borrow::<str>::borrow(&stroka)));
+
+        let mut hasher = DefaultHasher::new();
+        stroka.hash_str(&mut hasher);
+        assert_eq!(hasher.finish(), hash_of(text.as_str()));
+
+        let other = stroka::String::new_str(&rng.string());
+        assert_eq!(stroka == other, text == other.as_str());
+        assert_eq!(stroka.cmp(&other), text.as_str().cmp(other.as_str()));
+        if stroka == other {
+            assert_eq!(hash_of(&stroka), hash_of(&other));
+        }
+    }
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_hash_equally_across_variants() {
+    let mut rng = Rng(0xB0B);
+    for _ in 0..1000 {
+        let text = rng.string();
+        let sso = stroka::String::new_str(&text);
+        let mut heap = stroka::String::with_capacity(stroka::String::INLINE_CAPACITY + 1);
+        heap.push_str(&text);
+        assert!(heap.is_alloc());
+
+        assert_eq!(sso, heap);
+        assert_eq!(sso.cmp(&heap), core::cmp::Ordering::Equal);
+        assert_eq!(hash_of(&sso), hash_of(&heap));
+    }
+}
+
+#[test]
+pub fn should_lookup_maps_by_str() {
+    let mut rng = Rng(0xC0FFEE);
+    let texts = (0..200).map(|_| rng.string()).collect::<Vec<_>>();
+
+    let mut hash_map = HashMap::new();
+    let mut btree_map = BTreeMap::new();
+    for (idx, text) in texts.iter().enumerate() {
+        hash_map.insert(stroka::String::new_str(text), idx);
+        btree_map.insert(stroka::String::new_str(text), idx);
+    }
+
+    for text in texts.iter() {
+        assert_eq!(hash_map.get(text.as_str()), btree_map.get(text.as_str()));
+        assert!(hash_map.contains_key(text.as_str()));
+    }
+
+    let mut expected = texts.iter().map(|text| text.as_str()).filter(|text| *text >= "a" && *text < "b").collect::<Vec<_>>();
+    expected.sort_unstable();
+    expected.dedup();
+    let found = btree_map.range::<str, _>((Bound::Included("a"), Bound::Excluded("b"))).map(|(key, _)| key.as_str()).collect::<Vec<_>>();
+    assert!(!found.is_empty());
+    assert_eq!(found, expected);
+}
+
+//Records everything written into hasher
+#[derive(Default)]
+struct Recorder(Vec<u8>);
+
+impl Hasher for Recorder {
+    fn finish(&self) -> u64 {
+        0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+}
+
+#[test]
+pub fn should_write_raw_bytes_into_hasher() {
+    let mut rng = Rng(0xBEEF);
+    for _ in 0..200 {
+        let text = rng.string();
+        let stroka = stroka::String::new_str(&text);
+
+        let mut raw = Recorder::default();
+        stroka.write_hash_into(&mut raw);
+        assert_eq!(raw.0, text.as_bytes());
+
+        let mut direct = DefaultHasher::new();
+        direct.write(text.as_bytes());
+        let mut hasher = DefaultHasher::new();
+        stroka.write_hash_into(&mut hasher);
+        assert_eq!(hasher.finish(), direct.finish());
+
+        //Hash of str appends terminator, which raw bytes do not have
+        let mut hashed = Recorder::default();
+        stroka.hash(&mut hashed);
+        assert_ne!(hashed.0, raw.0);
+        assert!(hashed.0.starts_with(&raw.0));
+    }
+}
@@ -0,0 +1,164 @@
+#![cfg(feature = "std")]
+
+use std::io;
+
+struct Trickle {
+    out: Vec<u8>,
+    chunk: usize,
+    interrupt: bool,
+}
+
+impl io::Write for Trickle {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.interrupt {
+            self.interrupt = false;
+            return Err(io::ErrorKind::Interrupted.into());
+        }
+        self.interrupt = true;
+
+        let len = buf.len().min(self.chunk);
+        self.out.extend_from_slice(&buf[..len]);
+        Ok(len)
+    }
+
+    fn write_vectored(&mut self, bufs: &[io::IoSlice<'_>]) -> io::Result<usize> {
+        match bufs.iter().find(|buf| !buf.is_empty()) {
+            Some(buf) => self.write(buf),
+            None => Ok(0),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+pub fn should_write_to_io() {
+    let stroka = stroka::String::new_str("1単語8");
+    let mut writer = Trickle { out: Vec::new(), chunk: 3, interrupt: true };
+
+    stroka.write_to(&mut writer).expect("to write");
+    assert_eq!(writer.out, stroka.as_bytes());
+}
+
+#[test]
+pub fn should_write_to_io_with_prefix() {
+    let stroka = stroka::String::new_str("1単語8");
+    for chunk in 1..10 {
+        let mut writer = Trickle { out: Vec::new(), chunk, interrupt: true };
+        stroka.write_to_with(b"prefix:", &mut writer).expect("to write");
+        assert_eq!(writer.out, b"prefix:1\xe5\x8d\x98\xe8\xaa\x9e8");
+    }
+
+    let mut out = Vec::new();
+    stroka.write_to_with(b"", &mut out).expect("to write");
+    assert_eq!(out, stroka.as_bytes());
+
+    let mut out = Vec::new();
+    stroka::String::new().write_to_with(b"prefix", &mut out).expect("to write");
+    assert_eq!(out, b"prefix");
+}
+
+#[test]
+pub fn should_fail_write_to_closed_io() {
+    let stroka = stroka::String::new_str("lolka");
+    let mut out = [0u8; 3];
+    let error = stroka.write_to_with(b"-", &mut &mut out[..]).expect_err("to fail");
+    assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+    assert_eq!(&out, b"-lo");
+}
+
+#[test]
+pub fn should_write_utf8_via_io_write() {
+    use std::io::Write;
+
+    let mut stroka = stroka::String::new();
+    assert_eq!(stroka.write(b"lol").expect("to write"), 3);
+    stroka.write_all("単".as_bytes()).expect("to write");
+    write!(&mut stroka, "{}", 1).expect("to write");
+    stroka.flush().expect("to flush");
+    assert_eq!(stroka, "lol単1");
+    assert_eq!(stroka.write(b"").expect("to write"), 0);
+}
+
+#[test]
+pub fn should_reject_split_or_invalid_utf8_via_io_write() {
+    use std::io::Write;
+
+    let bytes = "語❤".as_bytes();
+    let mut stroka = stroka::String::new_str("l");
+    for split in 1..3 {
+        let error = stroka.write(&bytes[..split]).expect_err("to reject incomplete character");
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(stroka, "l");
+    }
+    assert_eq!(stroka.write(&bytes[..3]).expect("to write"), 3);
+    let error = stroka.write_all(&bytes[3..5]).expect_err("to reject incomplete character");
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    stroka.write_all(&bytes[3..]).expect("to write");
+    assert_eq!(stroka, "l語❤");
+
+    assert_eq!(stroka.write(b"lol\xFFka").expect("to write valid prefix"), 3);
+    let error = stroka.write(b"\xFFka").expect_err("to reject invalid byte");
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(stroka, "l語❤lol");
+}
+
+#[test]
+pub fn should_not_consume_incomplete_char_via_io_write() {
+    use std::io::Write;
+
+    let bytes = "ab❤".as_bytes();
+    let mut stroka = stroka::String::new();
+    assert_eq!(stroka.write(&bytes[..4]).expect("to write"), 2);
+    assert_eq!(stroka, "ab");
+    assert_eq!(stroka.write(&bytes[2..]).expect("to write"), 3);
+    assert_eq!(stroka, "ab❤");
+
+    //Character split between two writes cannot be carried over by string itself
+    let mut stroka = stroka::String::new();
+    let error = stroka.write_all(&bytes[..4]).expect_err("to reject incomplete character");
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(stroka, "ab");
+}
+
+#[test]
+pub fn should_carry_split_char_via_appender_io_write() {
+    use std::io::Write;
+
+    let bytes = "ab❤単".as_bytes();
+    let mut stroka = stroka::String::new();
+    let mut appender = stroka::Utf8Appender::new(&mut stroka);
+    appender.write_all(&bytes[..3]).expect("to write");
+    let error = appender.flush().expect_err("to report incomplete character");
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    appender.write_all(&bytes[3..7]).expect("to write");
+    appender.write_all(&bytes[7..]).expect("to write");
+    appender.flush().expect("to flush");
+    appender.finish().expect("to be complete");
+    assert_eq!(stroka, "ab❤単");
+
+    let mut appender = stroka::Utf8Appender::new(&mut stroka);
+    let error = appender.write_all(b"\xFF").expect_err("to reject invalid byte");
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+    let mut appender = stroka::Utf8Appender::lossy(&mut stroka);
+    appender.write_all(&bytes[..3]).expect("to write");
+    appender.flush().expect("to flush");
+    appender.finish().expect("to be lossy");
+    assert_eq!(stroka, "ab❤単ab\u{FFFD}");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_write_into_heap_via_io_write() {
+    use std::io::Write;
+
+    let mut stroka = stroka::String::new();
+    for chunk in ["lolka ", "lol ", "lolid ", "by ", "単語"] {
+        stroka.write_all(chunk.as_bytes()).expect("to write");
+    }
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka, "lolka lol lolid by 単語");
+}
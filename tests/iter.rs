@@ -0,0 +1,102 @@
+use std::borrow::Cow;
+
+#[test]
+pub fn should_collect_results() {
+    let res: Result<stroka::String, u8> = vec![Ok("1単"), Ok("語8")].into_iter().collect();
+    assert_eq!(res.expect("to collect"), "1単語8");
+
+    let res: Result<stroka::String, u8> = vec![Ok('1'), Ok('単'), Ok('語'), Ok('8')].into_iter().collect();
+    assert_eq!(res.expect("to collect"), "1単語8");
+
+    let res: Result<stroka::String, u8> = vec![Ok(Cow::Borrowed("1単")), Ok(Cow::Owned("語8".to_owned()))].into_iter().collect();
+    assert_eq!(res.expect("to collect"), "1単語8");
+
+    let res: Result<stroka::String, u8> = vec![Ok(stroka::String::new_str("1")), Err(2), Ok(stroka::String::new_str("8"))].into_iter().collect();
+    assert_eq!(res, Err(2));
+}
+
+#[test]
+pub fn should_try_from_iter() {
+    let res = stroka::String::try_from_iter(vec![Ok::<_, u8>(Cow::Borrowed("1単")), Ok(Cow::Owned("語8".to_owned()))]);
+    assert_eq!(res.expect("to collect"), "1単語8");
+
+    let res = stroka::String::try_from_iter("1単語8".chars().map(Ok::<_, u8>));
+    assert_eq!(res.expect("to collect"), "1単語8");
+
+    let mut consumed = 0;
+    let res = stroka::String::try_from_iter(["1", "単", "語", "8"].iter().map(|item| {
+        consumed += 1;
+        match *item {
+            "語" => Err(consumed),
+            item => Ok(item),
+        }
+    }));
+    assert_eq!(res, Err(3));
+    assert_eq!(consumed, 3);
+}
+
+#[test]
+pub fn should_iterate_owned_chars() {
+    const TEXT: &str = "1単語8❤";
+    assert_eq!(stroka::String::new_str(TEXT).into_chars().collect::<Vec<_>>(), TEXT.chars().collect::<Vec<_>>());
+    assert_eq!(stroka::String::new_str(TEXT).into_chars().rev().collect::<Vec<_>>(), TEXT.chars().rev().collect::<Vec<_>>());
+    assert_eq!(stroka::String::new_str(TEXT).into_chars().last(), Some('❤'));
+
+    let mut chars = stroka::String::new_str(TEXT).into_chars();
+    assert_eq!(chars.next(), Some('1'));
+    assert_eq!(chars.next_back(), Some('❤'));
+    assert_eq!(chars.as_str(), "単語8");
+    assert_eq!(chars.next_back(), Some('8'));
+    assert_eq!(chars.next(), Some('単'));
+    assert_eq!(chars.next_back(), Some('語'));
+    assert_eq!(chars.next(), None);
+    assert_eq!(chars.next_back(), None);
+    assert_eq!(chars.as_str(), "");
+
+    let mut collected = Vec::new();
+    for ch in stroka::String::new_str(TEXT) {
+        collected.push(ch);
+    }
+    assert_eq!(collected, TEXT.chars().collect::<Vec<_>>());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_iterate_owned_chars_of_heap_string() {
+    const TEXT: &str = "lolka lol lolid by loli 単語";
+    let chars = stroka::String::new_str(TEXT).into_chars();
+    assert_eq!(chars.as_str(), TEXT);
+    assert_eq!(chars.rev().collect::<stroka::String>(), TEXT.chars().rev().collect::<std::string::String>());
+}
+
+fn expected_boundaries(text: &str) -> Vec<usize> {
+    text.char_indices().map(|(idx, _)| idx).chain(core::iter::once(text.len())).collect()
+}
+
+#[test]
+pub fn should_iterate_char_boundaries() {
+    use stroka::StrExt;
+
+    const CASES: [&str; 5] = ["", "lolka", "単語", "1単語8", "❤😀a😀"];
+    for text in CASES.iter() {
+        let expected = expected_boundaries(text);
+        assert_eq!(StrExt::char_boundaries(*text).collect::<Vec<_>>(), expected);
+
+        let mut reversed = expected.clone();
+        reversed.reverse();
+        assert_eq!(StrExt::char_boundaries(*text).rev().collect::<Vec<_>>(), reversed);
+
+        let (lower, upper) = StrExt::char_boundaries(*text).size_hint();
+        assert!(lower <= expected.len() && upper >= Some(expected.len()));
+    }
+
+    let stroka = stroka::String::new_str("1単語8");
+    let mut boundaries = stroka.char_boundaries();
+    assert_eq!(boundaries.next(), Some(0));
+    assert_eq!(boundaries.next_back(), Some(8));
+    assert_eq!(boundaries.next_back(), Some(7));
+    assert_eq!(boundaries.next(), Some(1));
+    assert_eq!(boundaries.next(), Some(4));
+    assert_eq!(boundaries.next_back(), None);
+    assert_eq!(boundaries.next(), None);
+}
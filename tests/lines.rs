@@ -0,0 +1,40 @@
+fn collect_lines(text: &str) -> Vec<stroka::String> {
+    stroka::String::new_str(text).into_lines().collect()
+}
+
+#[test]
+pub fn should_iterate_owned_lines() {
+    const CASES: [&str; 8] = ["", "\n", "lolka", "lolka\n", "lol\nka", "lol\r\nka\r\n", "\n\nlol\n\r\n\nka", "lol\rka\r"];
+
+    for text in CASES.iter() {
+        let expected = text.lines().collect::<Vec<_>>();
+        assert_eq!(collect_lines(text), expected, "text={:?}", text);
+    }
+}
+
+#[test]
+pub fn should_yield_short_lines_inline() {
+    let mut lines = stroka::String::new_str("lol\r\n単語\n").into_lines();
+    assert_eq!(lines.as_str(), "lol\r\n単語\n");
+
+    let line = lines.next().expect("to have line");
+    assert_eq!(line, "lol");
+    assert!(!line.is_alloc());
+    assert_eq!(lines.as_str(), "単語\n");
+
+    let line = lines.next().expect("to have line");
+    assert_eq!(line, "単語");
+    assert_eq!(lines.as_str(), "");
+    assert!(lines.next().is_none());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_release_heap_source_after_last_line() {
+    let mut lines = stroka::String::new_str("lolka lol\nlolid by loli\nshort\n").into_lines();
+    for line in &mut lines {
+        assert!(!line.is_alloc());
+    }
+    assert_eq!(lines.as_str(), "");
+    assert!(lines.next().is_none());
+}
@@ -0,0 +1,79 @@
+#[test]
+pub fn should_display_lossy_bytes() {
+    assert_eq!(format!("{}", stroka::lossy(b"")), "");
+    assert_eq!(format!("{}", stroka::lossy("1単語8".as_bytes())), "1単語8");
+    assert_eq!(format!("{}", stroka::lossy(b"\xfflolka")), "\\xFFlolka");
+    assert_eq!(format!("{}", stroka::lossy(b"lol\xc3\x28ka")), "lol\\xC3(ka");
+    assert_eq!(format!("{}", stroka::lossy(b"lolka\xe5\x8d")), "lolka\\xE5\\x8D");
+    assert_eq!(format!("{}", stroka::lossy(b"\x80\x80")), "\\x80\\x80");
+}
+
+#[test]
+pub fn should_debug_lossy_bytes() {
+    assert_eq!(format!("{:?}", stroka::lossy(b"")), "\"\"");
+    assert_eq!(format!("{:?}", stroka::lossy("\"単\"\n".as_bytes())), "\"\\\"単\\\"\\n\"");
+    assert_eq!(format!("{:?}", stroka::lossy(b"\xfflol\tka\xe5\x8d")), "\"\\xFFlol\\tka\\xE5\\x8D\"");
+}
+
+fn chunks(bytes: &[u8]) -> Vec<(&str, &[u8])> {
+    stroka::utf8_chunks(bytes).map(|chunk| (chunk.valid(), chunk.invalid())).collect()
+}
+
+#[test]
+pub fn should_split_utf8_chunks() {
+    assert!(chunks(b"").is_empty());
+    assert_eq!(chunks("1単語8".as_bytes()), [("1単語8", &b""[..])]);
+
+    //Same as std's Utf8Chunks example
+    assert_eq!(chunks(b"Hello\xC0\x80 There\xE6\x83 Goodbye"), [
+        ("Hello", &b"\xC0"[..]),
+        ("", &b"\x80"[..]),
+        (" There", &b"\xE6\x83"[..]),
+        (" Goodbye", &b""[..]),
+    ]);
+    assert_eq!(chunks(b"\xF1\x80\x80"), [("", &b"\xF1\x80\x80"[..])]);
+    assert_eq!(chunks(b"lol\xE5\x8D"), [("lol", &b"\xE5\x8D"[..])]);
+    assert_eq!(chunks(b"\xFF\xFF"), [("", &b"\xFF"[..]), ("", &b"\xFF"[..])]);
+}
+
+#[test]
+pub fn should_convert_from_utf8_lossy() {
+    const CASES: [&[u8]; 7] = [b"", b"lolka", b"\xFFlol", b"lol\xC3\x28", b"lol\xE5\x8D", b"\xF1\x80\x80\x80", b"\xC0\x80"];
+    for bytes in CASES.iter() {
+        let stroka = stroka::String::from_utf8_lossy(bytes);
+        assert_eq!(stroka, std::string::String::from_utf8_lossy(bytes));
+        assert!(!stroka.is_alloc());
+    }
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_from_utf8_lossy_outside_sso_cap() {
+    const BYTES: &[u8] = b"Hello\xC0\x80 There\xE6\x83 Goodbye";
+    assert_eq!(stroka::String::from_utf8_lossy(BYTES), std::string::String::from_utf8_lossy(BYTES));
+}
+
+#[test]
+pub fn should_push_utf8_lossy() {
+    let mut stroka = stroka::String::new_str("1");
+    stroka.push_utf8_lossy(b"");
+    stroka.push_utf8_lossy("単".as_bytes());
+    stroka.push_utf8_lossy(b"\xE8\xAA");
+    assert_eq!(stroka, "1単\u{FFFD}");
+    assert!(!stroka.is_alloc());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_push_utf8_lossy_spilling_onto_heap() {
+    const CHUNKS: [&[u8]; 3] = [b"lolka \xFF", b"lol\xE5", b"\x8D\x98 lolid"];
+    let mut stroka = stroka::String::new();
+    let mut expected = std::string::String::new();
+    for chunk in CHUNKS.iter() {
+        stroka.push_utf8_lossy(chunk);
+        expected.push_str(&std::string::String::from_utf8_lossy(chunk));
+        assert_eq!(stroka, expected);
+    }
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka, "lolka \u{FFFD}lol\u{FFFD}\u{FFFD}\u{FFFD} lolid");
+}
@@ -4,6 +4,26 @@ use core::ops::Bound;
 pub fn should_have_format_macro() {
     let result = stroka::format!("{0} + {0} = {1}", 1, 2);
     assert_eq!(result, "1 + 1 = 2");
+
+    let result = stroka::format!("lolka {{}}");
+    assert_eq!(result, "lolka {}");
+
+    let result = stroka::format!("{}", 1.5);
+    assert_eq!(result, "1.5");
+    let result = stroka::format!("{}", "lolka",);
+    assert_eq!(result, "lolka");
+
+    let name = "lolka";
+    let result = stroka::format!("{name}");
+    assert_eq!(result, "lolka");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_size_format_macro_output() {
+    let result = stroka::format!("{}-{}-{}-{}-{}-{}", 1, 22, 333, 4444, 55555, 666666);
+    assert_eq!(result, "1-22-333-4444-55555-666666");
+    assert_eq!(result.capacity(), result.len());
 }
 
 #[test]
@@ -19,6 +39,7 @@ pub fn should_not_reserve_within_sso_capacity() {
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 pub fn should_push_various_chunks() {
     let chunks = [
         '1', '2', '3', '4', '5', '6', '7', '8', '9'
@@ -124,10 +145,11 @@ pub fn should_panic_on_non_char_bound_remove_from_sso_string() {
 pub fn should_panic_on_remove_from_outside_of_sso_string() {
     const TEXT: &str = "1単語8";
     let mut stroka = stroka::String::new_str(TEXT);
-    stroka.remove(usize::max_value());
+    stroka.remove(usize::MAX);
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 pub fn should_remove_from_heap_string() {
     const TEXT: &str = "123456789単語123456789";
     let mut stroka = stroka::String::new_str(TEXT);
@@ -150,6 +172,7 @@ pub fn should_remove_from_heap_string() {
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 pub fn should_drain_from_heap_string() {
     const TEXT: &str = "123456789単語123456789";
     let mut stroka = stroka::String::new_str(TEXT);
@@ -183,6 +206,7 @@ pub fn should_drain_from_heap_string() {
 
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 #[should_panic]
 pub fn should_panic_on_non_char_bound_remove_from_heap_string() {
     const TEXT: &str = "123456789単語123456789";
@@ -191,14 +215,16 @@ pub fn should_panic_on_non_char_bound_remove_from_heap_string() {
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 #[should_panic]
 pub fn should_panic_on_remove_from_outside_of_heap_string() {
     const TEXT: &str = "123456789単語123456789";
     let mut stroka = stroka::String::new_str(TEXT);
-    stroka.remove(usize::max_value());
+    stroka.remove(usize::MAX);
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 pub fn should_insert_at_any_valid_position() {
     const TEXT: &str = "1単語8";
 
@@ -236,6 +262,7 @@ pub fn should_retain_within_sso() {
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 pub fn should_retain_within_heap() {
     const TEXT: &str = "-1++1-単語8単語81単語1単語8+8-++";
     let mut stroka = stroka::String::new_str(TEXT);
@@ -250,14 +277,16 @@ pub fn should_retain_within_heap() {
 
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 #[should_panic]
 pub fn should_panic_on_insert_outside_of_bound() {
     const TEXT: &str = "123456789単語123456789";
     let mut stroka = stroka::String::new_str(TEXT);
-    stroka.insert_str(usize::max_value(), TEXT);
+    stroka.insert_str(usize::MAX, TEXT);
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 pub fn should_replace_range_within_heap_string() {
     use core::ops::Bound;
 
@@ -274,6 +303,7 @@ pub fn should_replace_range_within_heap_string() {
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 pub fn should_remove_range_within_heap_string() {
     use core::ops::Bound;
 
@@ -320,6 +350,7 @@ pub fn should_remove_range_within_sso_string() {
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 pub fn should_replace_range_within_sso_string() {
     const TEXT: &str = "1単語8";
     let mut stroka = stroka::String::new_str(TEXT);
@@ -362,3 +393,301 @@ pub fn should_panic_on_reverse_replace_range() {
 
     stroka.replace_range((Bound::Included(3), Bound::Excluded(1)), "3");
 }
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_resize_by_chars() {
+    let mut stroka = stroka::String::new_str("ab");
+    assert!(!stroka.is_alloc());
+
+    stroka.resize(2, 'ロ');
+    assert_eq!(stroka, "ab");
+
+    stroka.resize(7, 'ロ');
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka, "abロロロロロ");
+    assert_eq!(stroka.chars().count(), 7);
+
+    stroka.resize(3, '-');
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka, "abロ");
+
+    stroka.resize(0, '-');
+    assert_eq!(stroka, "");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_resize_by_bytes() {
+    let mut stroka = stroka::String::new_str("1単");
+
+    stroka.resize_bytes(4, b' ');
+    assert_eq!(stroka, "1単");
+
+    stroka.resize_bytes(20, b' ');
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka.len(), 20);
+    assert_eq!(stroka, format!("1単{}", " ".repeat(16)));
+
+    stroka.resize_bytes(1, b' ');
+    assert_eq!(stroka, "1");
+}
+
+#[test]
+#[should_panic]
+pub fn should_panic_on_resize_bytes_with_non_ascii() {
+    let mut stroka = stroka::String::new();
+    stroka.resize_bytes(4, 0xE3);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_repeat_in_place() {
+    let mut stroka = stroka::String::new_str("ab");
+    stroka.repeat_in_place(1);
+    assert_eq!(stroka, "ab");
+
+    stroka.repeat_in_place(3);
+    assert!(!stroka.is_alloc());
+    assert_eq!(stroka, "ababab");
+
+    stroka.repeat_in_place(5);
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka, "ab".repeat(15));
+
+    stroka.repeat_in_place(0);
+    assert_eq!(stroka, "");
+    stroka.repeat_in_place(10);
+    assert_eq!(stroka, "");
+}
+
+#[test]
+#[should_panic]
+pub fn should_panic_on_repeat_in_place_overflow() {
+    let mut stroka = stroka::String::new_str("ab");
+    stroka.repeat_in_place(usize::MAX);
+}
+
+#[test]
+pub fn should_replace_range_in_the_middle_of_sso_string() {
+    let mut stroka = stroka::String::new_str("abcdef");
+    stroka.replace_range(1..3, "X");
+    assert!(!stroka.is_alloc());
+    assert_eq!(stroka, "aXdef");
+
+    stroka.replace_range(1..2, "XYZ");
+    assert!(!stroka.is_alloc());
+    assert_eq!(stroka, "aXYZdef");
+
+    stroka.replace_range(2..4, "");
+    assert_eq!(stroka, "aXdef");
+
+    stroka.replace_range(stroka.len().., "単");
+    assert_eq!(stroka, "aXdef単");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_edit_large_heap_string_quickly() {
+    let mut stroka = stroka::String::new_str(&"単語".repeat(512 * 1024));
+    assert!(stroka.is_alloc());
+
+    for _ in 0..1000 {
+        stroka.truncate(stroka.len() - 3);
+        stroka.remove(0);
+        assert!(stroka.pop().is_some());
+    }
+    assert_eq!(stroka.len(), 6 * 512 * 1024 - 9000);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_pop_chars_from_the_end() {
+    const TEXT: &str = "1単語8ロりr123456789";
+    let mut stroka = stroka::String::new_str(TEXT);
+    assert!(stroka.is_alloc());
+
+    let mut expected = TEXT.chars().rev();
+    while let Some(ch) = stroka.pop() {
+        assert_eq!(Some(ch), expected.next());
+        assert_eq!(stroka, TEXT[..stroka.len()]);
+    }
+    assert!(expected.next().is_none());
+    assert!(stroka.is_empty());
+
+    let mut stroka = stroka::String::new_str("1単語");
+    assert!(!stroka.is_alloc());
+    assert_eq!(stroka.pop(), Some('語'));
+    assert_eq!(stroka.pop(), Some('単'));
+    assert_eq!(stroka.pop(), Some('1'));
+    assert_eq!(stroka.pop(), None);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_format_with_sized_path() {
+    let mut stroka = stroka::String::new();
+    stroka.write_fmt_sized(format_args!("{}-{}-{}-{}", 1, "単語", 'ロ', 2.5)).expect("to format");
+    assert_eq!(stroka, format!("{}-{}-{}-{}", 1, "単語", 'ロ', 2.5));
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka.capacity(), stroka.len());
+
+    let expected = (0..100).map(|idx| format!("{},", idx)).collect::<String>();
+    let stroka = stroka::String::from_fmt(format_args!("{}", expected));
+    assert_eq!(stroka, expected);
+
+    let stroka = stroka::String::from_fmt(format_args!("lolka"));
+    assert_eq!(stroka, "lolka");
+    assert!(!stroka.is_alloc());
+
+    let stroka = stroka::String::from_fmt(format_args!("{}+{}", 1, 2));
+    assert_eq!(stroka, "1+2");
+    assert!(!stroka.is_alloc());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_cut_range() {
+    const TEXT: &str = "123456789単語123456789";
+
+    let mut stroka = stroka::String::new_str(TEXT);
+    assert!(stroka.is_alloc());
+    let cut = stroka.cut_range(9..15);
+    assert!(!cut.is_alloc());
+    assert_eq!(cut, "単語");
+    assert_eq!(stroka, "123456789123456789");
+
+    let cut = stroka.cut_range(3..3);
+    assert_eq!(cut, "");
+    assert_eq!(stroka, "123456789123456789");
+
+    let cut = stroka.cut_range(..);
+    assert!(cut.is_alloc());
+    assert_eq!(cut, "123456789123456789");
+    assert_eq!(stroka, "");
+
+    let mut stroka = stroka::String::new_str("1単語8");
+    assert!(!stroka.is_alloc());
+    let cut = stroka.cut_range(1..=3);
+    assert_eq!(cut, "単");
+    assert_eq!(stroka, "1語8");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_cut_range_same_as_remove_range() {
+    const TEXT: &str = "1単語8ロりr";
+
+    for start in (0..=TEXT.len()).filter(|idx| TEXT.is_char_boundary(*idx)) {
+        for end in (start..=TEXT.len()).filter(|idx| TEXT.is_char_boundary(*idx)) {
+            let mut cut = stroka::String::new_str(TEXT);
+            let mut removed = stroka::String::new_str(TEXT);
+
+            assert_eq!(cut.cut_range(start..end), TEXT[start..end]);
+            removed.remove_range(start..end);
+            assert_eq!(cut, removed);
+        }
+    }
+}
+
+#[test]
+pub fn should_add_various_right_hand_sides() {
+    use std::borrow::Cow;
+
+    let stroka = stroka::String::new_str("lolka") + '❤';
+    assert_eq!(stroka, "lolka❤");
+    assert!(!stroka.is_alloc());
+
+    let mut stroka = stroka::String::new_str("lolka");
+    stroka += '単';
+    assert_eq!(stroka, "lolka単");
+
+    let other = stroka::String::new_str(" lol");
+    let stroka = stroka::String::new_str("lolka") + &other;
+    assert_eq!(stroka, "lolka lol");
+    assert!(!stroka.is_alloc());
+
+    let mut stroka = stroka::String::new_str("lolka");
+    stroka += &other;
+    assert_eq!(stroka, "lolka lol");
+
+    let stroka = stroka::String::new_str("lolka") + Cow::Borrowed(" lol");
+    assert_eq!(stroka, "lolka lol");
+    let stroka = stroka + Cow::Owned(std::string::String::from("❤"));
+    assert_eq!(stroka, "lolka lol❤");
+    assert!(!stroka.is_alloc());
+}
+
+#[test]
+pub fn should_add_std_string_references() {
+    let other = std::string::String::from(" lol");
+    let stroka = stroka::String::new_str("lolka") + &other;
+    assert_eq!(stroka, "lolka lol");
+
+    let mut stroka = stroka::String::new_str("lolka");
+    stroka += &other;
+    assert_eq!(stroka, "lolka lol");
+
+    let other: Box<str> = other.into_boxed_str();
+    let mut stroka = stroka::String::new_str("lolka") + &other;
+    stroka += &other;
+    assert_eq!(stroka, "lolka lol lol");
+}
+
+#[test]
+//Reference to `&str` is exactly what is being checked to compile
+#[allow(clippy::op_ref)]
+pub fn should_add_references_to_str_owners() {
+    use std::borrow::Cow;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let text: &str = "1";
+    let cow = Cow::Borrowed("2");
+    let rc: Rc<str> = Rc::from("3");
+    let arc: Arc<str> = Arc::from("4");
+
+    let mut stroka = stroka::String::new() + &text + &cow + &rc + &arc;
+    stroka += &text;
+    stroka += &cow;
+    stroka += &rc;
+    stroka += &arc;
+    assert_eq!(stroka, "12341234");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_spill_onto_heap_when_adding() {
+    const TEXT: &str = "lolka lol lolid";
+    const FULL: &str = "lolka lol lolid by loli";
+    assert!(TEXT.len() > stroka::String::INLINE_CAPACITY);
+    let prefix = &TEXT[..stroka::String::INLINE_CAPACITY];
+
+    let stroka = stroka::String::new_str(prefix) + 'd';
+    assert_eq!(stroka, &TEXT[..prefix.len() + 1]);
+    assert!(stroka.is_alloc());
+
+    let mut stroka = stroka::String::new_str(prefix);
+    stroka += '❤';
+    assert_eq!(stroka.as_str().strip_suffix('❤'), Some(prefix));
+    assert!(stroka.is_alloc());
+
+    let other = stroka::String::new_str(" by loli");
+    let stroka = stroka::String::new_str(TEXT) + &other;
+    assert_eq!(stroka, FULL);
+    assert!(stroka.is_alloc());
+
+    let mut stroka = stroka::String::new_str(TEXT);
+    stroka += &other;
+    assert_eq!(stroka, FULL);
+    assert!(stroka.is_alloc());
+
+    let mut stroka = stroka::String::new_str("lolka");
+    stroka += &stroka::String::new_str(&FULL[5..]);
+    assert_eq!(stroka, FULL);
+    assert!(stroka.is_alloc());
+
+    let stroka = stroka::String::new_str("lolka") + std::borrow::Cow::Borrowed(&FULL[5..]);
+    assert_eq!(stroka, FULL);
+    assert!(stroka.is_alloc());
+}
@@ -0,0 +1,46 @@
+mod common;
+use common::Rng;
+
+#[test]
+pub fn should_order_keys_as_strings() {
+    let mut rng = Rng(0x0BD3);
+    for _ in 0..2000 {
+        let (left, right) = (rng.string(), rng.string());
+        let (left_stroka, right_stroka) = (stroka::String::new_str(&left), stroka::String::new_str(&right));
+
+        let expected = left.as_str().cmp(right.as_str());
+        assert_eq!(left_stroka.cmp(&right_stroka), expected, "{:?} vs {:?}", left, right);
+        assert_eq!(left_stroka.as_ord_key().cmp(right_stroka.as_ord_key()), expected, "{:?} vs {:?}", left, right);
+        assert_eq!(left_stroka.cmp_bytes(right.as_bytes()), expected, "{:?} vs {:?}", left, right);
+        assert_eq!(left_stroka.partial_cmp(&right_stroka), Some(expected));
+    }
+}
+
+#[test]
+pub fn should_sort_keys_as_strings() {
+    let mut rng = Rng(0x50E7);
+    let mut strings: Vec<stroka::String> = (0..500).map(|_| stroka::String::new_str(&rng.string())).collect();
+    let mut keys = strings.clone();
+
+    strings.sort();
+    keys.sort_by(|left, right| left.as_ord_key().cmp(right.as_ord_key()));
+    assert_eq!(strings, keys);
+}
+
+#[test]
+pub fn should_compare_bytes() {
+    use core::cmp::Ordering;
+
+    let stroka = stroka::String::new_str("lolka");
+    assert_eq!(stroka.as_ord_key(), b"lolka");
+    assert_eq!(stroka.cmp_bytes(b"lolka"), Ordering::Equal);
+    assert_eq!(stroka.cmp_bytes(b"lol"), Ordering::Greater);
+    assert_eq!(stroka.cmp_bytes(b"lolkaa"), Ordering::Less);
+    assert_eq!(stroka.cmp_bytes(b""), Ordering::Greater);
+    assert_eq!(stroka.cmp_bytes(&[0xFF]), Ordering::Less);
+
+    //Precomposed character sorts after decomposed form, as its code point is larger.
+    let stroka = stroka::String::new_str("e\u{301}");
+    assert_eq!(stroka.cmp_bytes("é".as_bytes()), Ordering::Less);
+    assert_eq!(stroka::String::new_str("\u{10348}").cmp_bytes("\u{FFFD}".as_bytes()), Ordering::Greater);
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "std")]
+
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+#[test]
+pub fn should_compare_with_os_str() {
+    let stroka = stroka::String::new_str("単語.txt");
+
+    assert_eq!(stroka, *OsStr::new("単語.txt"));
+    assert_eq!(stroka, OsStr::new("単語.txt"));
+    assert_eq!(stroka, OsString::from("単語.txt"));
+    assert_eq!(*OsStr::new("単語.txt"), stroka);
+    assert_eq!(OsStr::new("単語.txt"), stroka);
+    assert_eq!(OsString::from("単語.txt"), stroka);
+
+    assert_ne!(stroka, OsStr::new("単語.tx"));
+    assert_ne!(stroka, OsString::from("語.txt"));
+    assert_ne!(OsStr::new(""), stroka);
+    assert_ne!(OsString::from("単語.TXT"), stroka);
+}
+
+#[test]
+pub fn should_compare_with_path() {
+    let stroka = stroka::String::new_str("/etc/単語");
+
+    assert_eq!(stroka, *Path::new("/etc/単語"));
+    assert_eq!(stroka, Path::new("/etc/単語"));
+    assert_eq!(stroka, PathBuf::from("/etc/単語"));
+    assert_eq!(*Path::new("/etc/単語"), stroka);
+    assert_eq!(Path::new("/etc/単語"), stroka);
+    assert_eq!(PathBuf::from("/etc/単語"), stroka);
+    //Paths are compared component-wise
+    assert_eq!(stroka, Path::new("/etc//単語/"));
+
+    assert_ne!(stroka, Path::new("/etc/hosts"));
+    assert_ne!(stroka, PathBuf::from("etc/単語"));
+    assert_ne!(Path::new("/etc"), stroka);
+    assert_ne!(PathBuf::from("/etc/単"), stroka);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_compare_heap_string_with_os_types() {
+    const TEXT: &str = "/home/lolka/lolid/単語.txt";
+    let stroka = stroka::String::new_str(TEXT);
+    assert!(stroka.is_alloc());
+
+    assert_eq!(stroka, OsString::from(TEXT));
+    assert_eq!(PathBuf::from(TEXT), stroka);
+    assert_ne!(stroka, Path::new("/home/lolka"));
+}
+
+fn check_into_os_types(text: &str) {
+    let stroka = stroka::String::new_str(text);
+
+    let os = OsString::from(&stroka);
+    assert_eq!(os.into_string().expect("valid UTF-8"), text);
+    let path = PathBuf::from(&stroka);
+    assert_eq!(path.into_os_string().into_string().expect("valid UTF-8"), text);
+
+    let path = PathBuf::from(stroka.clone());
+    assert_eq!(path, Path::new(text));
+    let os: OsString = stroka.into();
+    assert_eq!(os.into_string().expect("valid UTF-8"), text);
+}
+
+#[test]
+pub fn should_convert_into_os_types() {
+    check_into_os_types("");
+    check_into_os_types("/etc/単語");
+
+    let mut command = std::process::Command::new(stroka::String::new_str("ls"));
+    command.arg(stroka::String::new_str("-l"));
+    assert_eq!(command.get_program(), "ls");
+    assert_eq!(command.get_args().collect::<Vec<_>>(), [OsStr::new("-l")]);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_heap_string_into_os_types() {
+    check_into_os_types("/home/lolka/lolid/単語.txt");
+}
+
+#[test]
+pub fn should_convert_from_os_str() {
+    use core::convert::TryFrom;
+
+    for text in ["", "lolka", "/etc/単語"].iter() {
+        let os = OsStr::new(text);
+        assert_eq!(stroka::String::from_os_str(os).expect("valid Unicode"), *text);
+        assert_eq!(stroka::String::from_os_str_lossy(os), *text);
+        assert_eq!(stroka::String::try_from(os.to_os_string()).expect("valid Unicode"), *text);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_long_os_str() {
+    use core::convert::TryFrom;
+
+    const TEXT: &str = "/home/lolka/lolid/単語.txt";
+    let stroka = stroka::String::try_from(OsString::from(TEXT)).expect("valid Unicode");
+    assert_eq!(stroka, TEXT);
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka::String::from_os_str(OsStr::new(TEXT)).expect("valid Unicode"), TEXT);
+}
+
+#[cfg(unix)]
+#[test]
+pub fn should_reject_invalid_unicode_os_str() {
+    use core::convert::TryFrom;
+    use std::os::unix::ffi::OsStrExt;
+
+    let os = OsStr::from_bytes(b"lol\xffka");
+    assert_eq!(stroka::String::from_os_str(os), None);
+    assert_eq!(stroka::String::from_os_str_lossy(os), "lol\u{FFFD}ka");
+
+    let error = stroka::String::try_from(os.to_os_string()).expect_err("invalid Unicode");
+    assert_eq!(error, os);
+}
@@ -0,0 +1,78 @@
+use stroka::pool::StringPool;
+
+#[test]
+pub fn should_not_pool_sso_strings() {
+    let mut pool = StringPool::new(4, 64);
+    assert!(pool.is_empty());
+    pool.put(stroka::String::new_str("lolka"));
+    assert_eq!(pool.len(), 0);
+    assert_eq!(pool.get(), "");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_reuse_pooled_heap_string() {
+    let mut pool = StringPool::new(4, 64);
+    let mut string = pool.get_with_capacity(32);
+    string.push_str("lolka lol lolid by loli");
+    let ptr = string.as_ptr();
+    let capacity = string.capacity();
+
+    pool.put(string);
+    assert_eq!(pool.len(), 1);
+
+    let string = pool.get();
+    assert!(pool.is_empty());
+    assert_eq!(string, "");
+    assert_eq!(string.as_ptr(), ptr);
+    assert_eq!(string.capacity(), capacity);
+
+    pool.put(string);
+    let string = pool.get_with_capacity(16);
+    assert_eq!(string.as_ptr(), ptr);
+    assert_eq!(string.capacity(), capacity);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_respect_pool_limits() {
+    let mut pool = StringPool::new(2, 64);
+    assert_eq!(pool.max_len(), 2);
+    assert_eq!(pool.max_capacity(), 64);
+
+    pool.put(stroka::String::with_capacity(256));
+    assert_eq!(pool.get().capacity(), 64);
+
+    for _ in 0..4 {
+        pool.put(stroka::String::with_capacity(32));
+    }
+    assert_eq!(pool.len(), 2);
+    assert_eq!(pool.get().capacity(), 32);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_not_pool_strings_shrunk_inline() {
+    let mut pool = StringPool::new(4, 8);
+    pool.put(stroka::String::with_capacity(64));
+    assert!(pool.is_empty());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_pool_with_preallocated_storage() {
+    let mut pool = StringPool::with_capacity(2, 64);
+    assert!(pool.is_empty());
+    assert_eq!(pool.max_len(), 2);
+    assert_eq!(pool.max_capacity(), 64);
+
+    for _ in 0..3 {
+        pool.put(stroka::String::with_capacity(32));
+    }
+    assert_eq!(pool.len(), 2);
+    assert_eq!(pool.get().capacity(), 32);
+
+    let pool = stroka::pool::StringPool::<22>::with_capacity_small(1, 64);
+    assert!(pool.is_empty());
+    assert_eq!(pool.max_len(), 1);
+}
@@ -0,0 +1,72 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+fn convert<T: Into<stroka::String>>(value: T) -> stroka::String {
+    value.into()
+}
+
+#[test]
+pub fn should_convert_from_shared_str() {
+    const TEXT: &str = "1単語8";
+    let rc: Rc<str> = Rc::from(TEXT);
+    let arc: Arc<str> = Arc::from(TEXT);
+    let boxed: Box<str> = Box::from(TEXT);
+
+    for stroka in [convert(&rc), convert(rc.clone()), convert(&arc), convert(arc.clone()), convert(boxed)].iter() {
+        assert_eq!(stroka, TEXT);
+        assert!(!stroka.is_alloc());
+    }
+    assert_eq!(Rc::strong_count(&rc), 1);
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_from_long_shared_str() {
+    const TEXT: &str = "lolka lol lolid by loli 単語";
+    let rc: Rc<str> = Rc::from(TEXT);
+    let arc: Arc<str> = Arc::from(TEXT);
+
+    for stroka in [convert(&rc), convert(rc.clone()), convert(&arc), convert(arc.clone())].iter() {
+        assert_eq!(stroka, TEXT);
+        assert!(stroka.is_alloc());
+    }
+}
+
+fn check_into_shared(stroka: stroka::String) {
+    let text = std::string::String::from(stroka.as_str());
+
+    let boxed: Box<str> = (&stroka).into();
+    assert_eq!(&*boxed, text);
+    let rc: Rc<str> = (&stroka).into();
+    assert_eq!(&*rc, text);
+    assert_eq!(Rc::strong_count(&rc), 1);
+    let arc: Arc<str> = (&stroka).into();
+    assert_eq!(&*arc, text);
+    assert_eq!(Arc::strong_count(&arc), 1);
+
+    let rc: Rc<str> = stroka.clone().into();
+    assert_eq!(&*rc, text);
+    assert_eq!(Rc::strong_count(&rc), 1);
+    let arc: Arc<str> = stroka.clone().into();
+    assert_eq!(&*arc, text);
+    assert_eq!(Arc::strong_count(&arc), 1);
+    let boxed: Box<str> = stroka.into();
+    assert_eq!(&*boxed, text);
+}
+
+#[test]
+pub fn should_convert_into_shared_str() {
+    check_into_shared(stroka::String::new());
+    let stroka = stroka::String::new_str("1単語8");
+    assert!(!stroka.is_alloc());
+    check_into_shared(stroka);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_long_into_shared_str() {
+    let stroka = stroka::String::new_str("lolka lol lolid by loli 単語");
+    assert!(stroka.is_alloc());
+    check_into_shared(stroka);
+}
@@ -5,9 +5,51 @@ pub fn should_have_size_of_2_words() {
     let stroka = stroka::String::new();
     assert_eq!(mem::size_of::<stroka::String>(), mem::size_of::<usize>() * 2);
     assert_eq!(stroka.capacity(), mem::size_of::<usize>() * 2 - 2);
+    assert_eq!(stroka.capacity(), stroka::String::INLINE_CAPACITY);
+    assert_eq!(stroka::String::inline_capacity(), stroka::String::INLINE_CAPACITY);
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_check_whether_string_fits_inline() {
+    const CAP: usize = stroka::String::INLINE_CAPACITY;
+    const _: () = assert!(stroka::String::fits_inline("lolka"));
+    match mem::size_of::<usize>() {
+        8 => assert_eq!(CAP, 14),
+        4 => assert_eq!(CAP, 6),
+        _ => (),
+    }
+
+    let text = "l".repeat(CAP + 1);
+    for len in [0, 1, CAP - 1, CAP, CAP + 1] {
+        let fits = stroka::String::fits_inline(&text[..len]);
+        assert_eq!(fits, len <= CAP);
+        assert_eq!(fits, !stroka::String::new_str(&text[..len]).is_alloc());
+    }
+
+    let text = "単".repeat(CAP / 3 + 1);
+    assert!(!stroka::String::fits_inline(&text));
+    assert!(stroka::String::fits_inline(&text[..(CAP / 3) * 3]));
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_keep_option_size_of_2_words() {
+    assert_eq!(mem::size_of::<Option<stroka::String>>(), mem::size_of::<usize>() * 2);
+    assert_eq!(mem::size_of::<Option<Option<stroka::String>>>(), mem::size_of::<usize>() * 2);
+
+    let mut inline = Some(stroka::String::new_str("lolka"));
+    assert_eq!(inline.as_deref(), Some("lolka"));
+    assert_eq!(inline.as_ref().map(stroka::String::is_alloc), Some(false));
+    let heap = Some(Some(stroka::String::new_str("lolka lol lolid by loli")));
+    assert_eq!(heap.as_ref().and_then(Option::as_ref).map(stroka::String::is_alloc), Some(true));
+    assert_eq!(heap.flatten().as_deref(), Some("lolka lol lolid by loli"));
+    assert_eq!(inline.take().map(|string| string.len()), Some(5));
+    assert!(inline.is_none());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
 pub fn should_become_heap_allocated_on_buffer_overflow() {
     let mut stroka = stroka::String::new();
     let sso_capaicty = stroka.capacity();
@@ -31,7 +73,7 @@ pub fn should_become_heap_allocated_on_buffer_overflow() {
         assert_eq!(stroka.len(), expected_string.len());
     }
 
-    let idx = format!("{}", (5 + 1) % 9);
+    let idx = format!("{}", 6);
     expected_string.push_str(&idx);
     stroka.push_str(&idx);
 
@@ -77,6 +119,7 @@ pub fn should_clear_sso_string() {
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 pub fn should_clear_heap_string() {
     const TEXT: &str = "123456789123456789123456789";
     let mut stroka = stroka::String::new_str(TEXT);
@@ -97,8 +140,9 @@ pub fn should_clear_heap_string() {
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 pub fn should_create_non_heap_within_sso_capacity() {
-    const MAX_CAP: usize = core::mem::size_of::<usize>() * 2 - 2;
+    const MAX_CAP: usize = stroka::String::INLINE_CAPACITY;
 
     for idx in 0..=MAX_CAP {
         let stroka = stroka::String::with_capacity(idx);
@@ -112,8 +156,9 @@ pub fn should_create_non_heap_within_sso_capacity() {
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 pub fn should_create_not_reserve_heap_within_sso_capacity() {
-    const MAX_CAP: usize = core::mem::size_of::<usize>() * 2 - 2;
+    const MAX_CAP: usize = stroka::String::INLINE_CAPACITY;
     let mut stroka = stroka::String::with_capacity(0);
 
     for idx in 0..=MAX_CAP {
@@ -132,15 +177,165 @@ pub fn should_create_not_reserve_heap_within_sso_capacity() {
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_round_heap_growth_to_size_class() {
+    const MAX_CAP: usize = stroka::String::INLINE_CAPACITY;
+
+    for required in MAX_CAP + 1..=300 {
+        let mut stroka = stroka::String::new();
+        stroka.reserve(required);
+        assert!(stroka.capacity() >= required);
+        let align = if required > 128 { 16 } else { 8 };
+        assert_eq!(stroka.capacity() % align, 0, "required={}", required);
+        assert!(stroka.capacity() - required < align);
+
+        let mut stroka = stroka::String::new();
+        stroka.try_reserve(required).expect("to reserve");
+        assert!(stroka.capacity() >= required);
+        assert_eq!(stroka.capacity() % align, 0, "required={}", required);
+
+        let mut stroka = stroka::String::new();
+        stroka.reserve_exact(required);
+        assert_eq!(stroka.capacity(), required);
+        stroka.reserve_exact(1);
+        assert_eq!(stroka.capacity(), required);
+        stroka.push_str(&"l".repeat(required));
+        stroka.reserve_exact(1);
+        assert_eq!(stroka.capacity(), required + 1);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_grow_byte_at_a_time_with_few_reallocations() {
+    const LEN: usize = 1024;
+
+    fn count_growths(rounded: bool, mut push: impl FnMut(&mut stroka::String)) -> usize {
+        let mut stroka = stroka::String::new();
+        let mut capacity = stroka.capacity();
+        let mut growths = 0;
+        for _ in 0..LEN {
+            push(&mut stroka);
+            assert!(stroka.capacity() >= stroka.len());
+            if stroka.capacity() != capacity {
+                capacity = stroka.capacity();
+                growths += 1;
+                if rounded && stroka.is_alloc() {
+                    assert_eq!(capacity % 8, 0, "capacity={}", capacity);
+                }
+            }
+        }
+        assert_eq!(stroka.len(), LEN);
+        growths
+    }
+
+    let exact = count_growths(false, |stroka| {
+        stroka.reserve_exact(1);
+        stroka.push('l');
+    });
+    assert_eq!(exact, LEN - stroka::String::INLINE_CAPACITY);
+
+    let growths = count_growths(true, |stroka| stroka.push('l'));
+    assert!(growths <= 8, "growths={}", growths);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
 pub fn should_shrink_heap_capacity() {
-    const MAX_CAP: usize = core::mem::size_of::<usize>() * 2 - 2;
+    const MAX_CAP: usize = stroka::String::INLINE_CAPACITY;
     let mut stroka = stroka::String::with_capacity(MAX_CAP+1);
     assert!(stroka.capacity() > MAX_CAP);
 
     assert!(stroka.is_alloc());
     stroka.shrink_to_fit();
+    assert!(!stroka.is_alloc());
+    assert_eq!(stroka.capacity(), MAX_CAP);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_shrink_short_heap_string_back_inline() {
+    const MAX_CAP: usize = stroka::String::INLINE_CAPACITY;
+    const TEXT: &str = "lolka lol lolid by loli";
+    let mut stroka = stroka::String::new_str(TEXT);
+    assert!(stroka.is_alloc());
+
+    stroka.shrink_to_fit();
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka.capacity(), TEXT.len());
+
+    stroka.truncate(5);
+    stroka.shrink_to(MAX_CAP + 1);
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka.capacity(), MAX_CAP + 1);
+
+    stroka.shrink_to_fit();
+    assert!(!stroka.is_alloc());
+    assert_eq!(stroka, "lolka");
+    assert_eq!(stroka.capacity(), MAX_CAP);
+    let ptr = stroka.as_ptr() as usize;
+    let inline = &stroka as *const _ as usize;
+    assert!(ptr >= inline && ptr < inline + core::mem::size_of::<stroka::String>());
+
+    let mut stroka = stroka::String::new_str(&"単語".repeat(10));
+    stroka.truncate("単語".len() * 2);
+    stroka.shrink_to(3);
+    assert!(!stroka.is_alloc());
+    assert_eq!(stroka, "単語単語");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_shrink_heap_capacity_to_min() {
+    const TEXT: &str = "lolka lol lolid by loli";
+    let mut stroka = stroka::String::with_capacity(256);
+    stroka.push_str(TEXT);
+
+    stroka.shrink_to(64);
+    assert_eq!(stroka.capacity(), 64);
+    stroka.shrink_to(128);
+    assert_eq!(stroka.capacity(), 64);
+    stroka.shrink_to(1);
+    assert_eq!(stroka.capacity(), TEXT.len());
     assert!(stroka.is_alloc());
-    assert_eq!(stroka.capacity(), 0);
+    assert_eq!(stroka, TEXT);
+
+    let mut stroka = stroka::String::new_str("lolka");
+    let capacity = stroka.capacity();
+    stroka.shrink_to(0);
+    assert!(!stroka.is_alloc());
+    assert_eq!(stroka.capacity(), capacity);
+    assert_eq!(stroka, "lolka");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_into_compact() {
+    const MAX_CAP: usize = stroka::String::INLINE_CAPACITY;
+    let mut stroka = stroka::String::with_capacity(64);
+    stroka.push_str("lolka");
+    assert!(stroka.is_alloc());
+    let stroka = stroka.into_compact();
+    assert!(!stroka.is_alloc());
+    assert_eq!(stroka, "lolka");
+
+    const TEXT: &str = "lolka lol lolid by loli";
+    let mut stroka = stroka::String::with_capacity(64);
+    stroka.push_str(TEXT);
+    let stroka = stroka.into_compact();
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka, TEXT);
+    assert_eq!(stroka.capacity(), stroka.len());
+
+    let ptr = stroka.as_ptr();
+    let stroka = stroka.into_compact();
+    assert_eq!(stroka.as_ptr(), ptr);
+    assert_eq!(stroka.capacity(), TEXT.len());
+
+    let stroka = stroka::String::new_str("lolka").into_compact();
+    assert!(!stroka.is_alloc());
+    assert_eq!(stroka.capacity(), MAX_CAP);
+    assert_eq!(stroka, "lolka");
 }
 
 #[test]
@@ -161,9 +356,14 @@ pub fn should_not_truncate_above_sso_string_len() {
     assert_eq!(stroka.len(), 6);
     stroka.truncate(7);
     assert_eq!(stroka.len(), 6);
+    stroka.truncate(6);
+    assert_eq!(stroka.len(), 6);
+    stroka.truncate(usize::MAX);
+    assert_eq!(stroka, "ろり");
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 pub fn should_truncate_heap_string() {
     let mut stroka = stroka::String::new_str("123456789123456789ろり");
     assert!(stroka.is_alloc());
@@ -175,10 +375,72 @@ pub fn should_truncate_heap_string() {
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 pub fn should_not_truncate_above_heap_string_len() {
     let mut stroka = stroka::String::new_str("123456789123456789ろり");
     assert!(stroka.is_alloc());
     assert_eq!(stroka.len(), 24);
     stroka.truncate(25);
     assert_eq!(stroka.len(), 24);
+    stroka.truncate(24);
+    assert_eq!(stroka.len(), 24);
+    stroka.truncate(usize::MAX);
+    assert_eq!(stroka, "123456789123456789ろり");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_truncate_front_chars() {
+    let mut stroka = stroka::String::new_str("1単語8");
+    stroka.truncate_front_chars(4);
+    assert_eq!(stroka, "1単語8");
+    stroka.truncate_front_chars(10);
+    assert_eq!(stroka, "1単語8");
+    stroka.truncate_front_chars(2);
+    assert_eq!(stroka, "語8");
+    stroka.truncate_front_chars(0);
+    assert_eq!(stroka, "");
+    stroka.truncate_front_chars(1);
+    assert_eq!(stroka, "");
+
+    const TEXT: &str = "lolka lol lolid by loli 単語";
+    let mut stroka = stroka::String::new_str(TEXT);
+    let ptr = stroka.as_ptr();
+    let capacity = stroka.capacity();
+    stroka.truncate_front_chars(7);
+    assert_eq!(stroka, "loli 単語");
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka.as_ptr(), ptr);
+    assert_eq!(stroka.capacity(), capacity);
+    stroka.truncate_front_chars(1);
+    assert_eq!(stroka, "語");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_try_inline_at_boundary_lengths() {
+    const MAX_CAP: usize = stroka::String::INLINE_CAPACITY;
+    let text = "l".repeat(MAX_CAP + 1);
+
+    let mut stroka = stroka::String::new();
+    stroka.push_str(&text);
+    assert!(stroka.is_alloc());
+    assert!(!stroka.try_inline());
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka, text.as_str());
+
+    stroka.pop();
+    assert!(stroka.is_alloc());
+    assert!(stroka.try_inline());
+    assert!(!stroka.is_alloc());
+    assert_eq!(stroka.len(), MAX_CAP);
+    assert_eq!(stroka, &text[..MAX_CAP]);
+
+    assert!(stroka.try_inline());
+    assert!(!stroka.is_alloc());
+
+    let mut stroka = stroka::String::with_capacity(64);
+    assert!(stroka.try_inline());
+    assert!(!stroka.is_alloc());
+    assert_eq!(stroka, "");
 }
@@ -0,0 +1,144 @@
+type Path = stroka::SmallString<22>;
+type Tiny = stroka::SmallString<6>;
+
+macro_rules! mutation_scenarios {
+    ($name:ident, $typ:ty, $cap:expr) => {
+        mod $name {
+            use core::hash::{Hash, Hasher};
+            use std::collections::hash_map::DefaultHasher;
+
+            type Str = $typ;
+            const CAP: usize = $cap;
+
+            fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+                let mut hasher = DefaultHasher::new();
+                value.hash(&mut hasher);
+                hasher.finish()
+            }
+
+            #[test]
+            pub fn should_report_inline_capacity() {
+                assert_eq!(Str::INLINE_CAPACITY, CAP);
+                assert_eq!(Str::new().capacity(), CAP);
+                assert!(Str::fits_inline(&"l".repeat(CAP)));
+                assert!(!Str::fits_inline(&"l".repeat(CAP + 1)));
+            }
+
+            #[test]
+            pub fn should_mutate_inline() {
+                let mut stroka = Str::new();
+                stroka.push('l');
+                stroka.push_str("ka");
+                stroka.insert(1, 'o');
+                stroka.insert_str(2, "l");
+                assert_eq!(stroka, "lolka");
+                assert!(!stroka.is_alloc());
+
+                assert_eq!(stroka.remove(0), 'l');
+                assert_eq!(stroka.pop(), Some('a'));
+                assert_eq!(stroka, "olk");
+                stroka.retain(|ch| ch != 'l');
+                assert_eq!(stroka, "ok");
+                stroka.replace_range(..1, "j");
+                assert_eq!(stroka, "jk");
+                assert_eq!(stroka.drain(..).collect::<std::string::String>(), "jk");
+                assert!(stroka.is_empty());
+                assert!(!stroka.is_alloc());
+            }
+
+            #[test]
+            pub fn should_provide_traits() {
+                let stroka = Str::from("lolka");
+                let copy = stroka.clone();
+                assert_eq!(stroka, copy);
+                assert_eq!(*stroka, *"lolka");
+                assert_eq!("lolka", stroka);
+                assert_eq!(hash(&stroka), hash("lolka"));
+                assert_eq!(format!("{} {:?} {:>6}", stroka, stroka, stroka), "lolka \"lolka\"  lolka");
+                let (lesser, greater) = (Str::from("a"), Str::from("b"));
+                assert!(lesser < greater);
+                assert_eq!(stroka.clone().into_chars().rev().collect::<Str>(), "aklol");
+                assert_eq!(Str::from("l\nk").into_lines().collect::<Vec<_>>(), ["l", "k"]);
+                assert_eq!(Str::default(), "");
+            }
+
+            #[test]
+            #[cfg(not(feature = "sso-only"))]
+            #[cfg(not(feature = "sso-only"))]
+            #[cfg(not(feature = "sso-only"))]
+            pub fn should_spill_and_come_back() {
+                let text = "l".repeat(CAP);
+                let mut stroka = Str::new_str(&text);
+                assert!(!stroka.is_alloc());
+                stroka.push('❤');
+                assert!(stroka.is_alloc());
+                assert_eq!(stroka.as_str(), format!("{}❤", text));
+
+                stroka.insert_str(0, "単語");
+                stroka.replace_range(.."単語".len(), "lol");
+                assert_eq!(stroka.remove(0), 'l');
+                stroka.retain(|ch| ch != '❤');
+                assert_eq!(stroka.as_str(), format!("ol{}", text));
+                assert!(stroka.is_alloc());
+
+                stroka.truncate(CAP);
+                stroka.shrink_to_fit();
+                assert!(!stroka.is_alloc());
+                assert_eq!(stroka.len(), CAP);
+                assert_eq!(stroka.capacity(), CAP);
+
+                let mut stroka = Str::with_capacity(CAP + 1);
+                assert!(stroka.is_alloc());
+                stroka.push_str("lolka");
+                assert!(stroka.try_inline());
+                assert_eq!(stroka, "lolka");
+            }
+        }
+    };
+}
+
+mutation_scenarios!(path, super::Path, 22);
+mutation_scenarios!(tiny, super::Tiny, 6);
+mutation_scenarios!(default, stroka::String, core::mem::size_of::<usize>() * 2 - 2);
+
+#[test]
+pub fn should_use_more_words_for_larger_buffer() {
+    assert_eq!(core::mem::size_of::<Path>(), 24);
+    assert_eq!(core::mem::size_of::<stroka::SmallString<30>>(), 32);
+    assert!(!Path::new_str("segment/of/23/bytes/lo").is_alloc());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_char_exceeding_tiny_buffer() {
+    let stroka = stroka::SmallString::<2>::from('❤');
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka, "❤");
+
+    let stroka = stroka::SmallString::<2>::from('l');
+    assert!(!stroka.is_alloc());
+    assert_eq!(stroka, "l");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_pool_small_strings() {
+    let mut pool = stroka::pool::StringPool::<22>::new_small(2, 64);
+    let mut string: Path = pool.get_with_capacity(32);
+    string.push_str("lolka lol lolid by loli");
+    let ptr = string.as_ptr();
+
+    pool.put(string);
+    assert_eq!(pool.len(), 1);
+    assert_eq!(pool.get().as_ptr(), ptr);
+}
+
+#[test]
+pub fn should_append_chunks_into_small_string() {
+    let mut stroka = Tiny::new();
+    let mut appender = stroka::Utf8Appender::new(&mut stroka);
+    appender.push_chunk(b"1\xE5\x8D").expect("to be incomplete");
+    appender.push_chunk(b"\x98").expect("to complete");
+    appender.finish().expect("to be complete");
+    assert_eq!(stroka, "1単");
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "sso-only")]
+
+const MAX_CAP: usize = core::mem::size_of::<usize>() * 2 - 2;
+
+#[test]
+pub fn should_never_allocate() {
+    const STROKA: stroka::String = stroka::String::new_sso("lolka");
+    assert!(!STROKA.is_alloc());
+
+    let mut stroka = stroka::String::with_capacity(MAX_CAP);
+    stroka.reserve(MAX_CAP);
+    for _ in 0..MAX_CAP {
+        stroka.push('1');
+        assert!(!stroka.is_alloc());
+    }
+    assert_eq!(stroka.len(), MAX_CAP);
+}
+
+#[test]
+#[should_panic]
+pub fn should_panic_on_with_capacity_overflow() {
+    stroka::String::with_capacity(MAX_CAP + 1);
+}
+
+#[test]
+#[should_panic]
+pub fn should_panic_on_new_str_overflow() {
+    stroka::String::new_str(&"1".repeat(MAX_CAP + 1));
+}
+
+#[test]
+#[should_panic]
+pub fn should_panic_on_push_str_overflow() {
+    let mut stroka = stroka::String::new_str(&"1".repeat(MAX_CAP));
+    stroka.push('1');
+}
+
+#[test]
+#[should_panic]
+pub fn should_panic_on_insert_str_overflow() {
+    let mut stroka = stroka::String::new_str(&"1".repeat(MAX_CAP));
+    stroka.insert_str(0, "1");
+}
+
+#[test]
+#[should_panic]
+pub fn should_panic_on_reserve_overflow() {
+    let mut stroka = stroka::String::new();
+    stroka.reserve(MAX_CAP + 1);
+}
+
+#[test]
+pub fn should_fail_try_reserve_beyond_sso_capacity() {
+    let mut stroka = stroka::String::new_str("lolka");
+    stroka.try_reserve(1).expect("to fit inline");
+    assert_eq!(stroka.try_reserve(64), Err(stroka::TryReserveError::CapacityOverflow));
+    assert_eq!(stroka.try_reserve_exact(64), Err(stroka::TryReserveError::CapacityOverflow));
+    assert_eq!(stroka, "lolka");
+}
+
+#[test]
+#[should_panic(expected = "exceeds inline capacity 4 ")]
+pub fn should_report_generic_inline_capacity_on_overflow() {
+    stroka::SmallString::<4>::new_str("lolka");
+}
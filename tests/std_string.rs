@@ -0,0 +1,54 @@
+#[test]
+pub fn should_convert_short_std_string_inline() {
+    let stroka = stroka::String::from(std::string::String::from("lolka"));
+    assert_eq!(stroka, "lolka");
+    assert!(!stroka.is_alloc());
+
+    let stroka = stroka::String::from_std(std::string::String::with_capacity(128));
+    assert_eq!(stroka, "");
+    assert!(!stroka.is_alloc());
+
+    let std: std::string::String = stroka::String::new_str("単語").into();
+    assert_eq!(std, "単語");
+    assert_eq!(std.capacity(), "単語".len());
+    assert_eq!(stroka::String::new().into_std(), "");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_long_std_string() {
+    const TEXT: &str = "lolka lol lolid by loli ❤";
+    let stroka = stroka::String::from(std::string::String::from(TEXT));
+    assert_eq!(stroka, TEXT);
+    assert!(stroka.is_alloc());
+
+    let std = stroka.into_std();
+    assert_eq!(std, TEXT);
+    assert_eq!(std.capacity(), TEXT.len());
+
+    let std = std::string::String::from(stroka::String::from_std(std));
+    assert_eq!(std, TEXT);
+}
+
+fn check_into_cow_and_bytes(text: &str) {
+    use std::borrow::Cow;
+
+    let cow: Cow<'_, str> = stroka::String::new_str(text).into();
+    assert!(matches!(cow, Cow::Owned(_)));
+    assert_eq!(cow, text);
+
+    let bytes: Vec<u8> = stroka::String::new_str(text).into();
+    assert_eq!(bytes, text.as_bytes());
+}
+
+#[test]
+pub fn should_convert_into_cow_and_bytes() {
+    check_into_cow_and_bytes("");
+    check_into_cow_and_bytes("単語");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_long_into_cow_and_bytes() {
+    check_into_cow_and_bytes("lolka lol lolid by loli ❤");
+}
@@ -1,6 +1,7 @@
 use stroka::StrExt;
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 fn should_make_uppercase() {
     let s = "Grüße, Jürgen ❤";
 
@@ -9,6 +10,7 @@ fn should_make_uppercase() {
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 fn should_make_lowercase() {
     let s = "Grüße, Jürgen ❤";
 
@@ -17,6 +19,35 @@ fn should_make_lowercase() {
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
+fn should_change_case_into_buffer() {
+    const INPUTS: [&str; 4] = ["Grüße, Jürgen ❤", "GRÜSSE, JÜRGEN ❤", "Hello, World! Long text", "hELLO, wORLD! lONG TEXT"];
+    let mut out = stroka::String::with_capacity(64);
+    let ptr = out.as_ptr();
+    let capacity = out.capacity();
+
+    for input in INPUTS.iter() {
+        out.clear();
+        StrExt::to_lowercase_into(*input, &mut out);
+        assert_eq!(out, str::to_lowercase(input));
+        assert_eq!(out.as_ptr(), ptr);
+        assert_eq!(out.capacity(), capacity);
+
+        out.clear();
+        StrExt::to_uppercase_into(*input, &mut out);
+        assert_eq!(out, str::to_uppercase(input));
+        assert_eq!(out.as_ptr(), ptr);
+        assert_eq!(out.capacity(), capacity);
+    }
+
+    out.clear();
+    StrExt::to_lowercase_into("ABC", &mut out);
+    StrExt::to_uppercase_into("def", &mut out);
+    assert_eq!(out, "abcDEF");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
 fn should_repeat() {
     assert_eq!(StrExt::repeat("0123456789abcdef", 0), "");
     assert_eq!(StrExt::repeat("0123456789abcdef", 1), "0123456789abcdef");
@@ -33,3 +64,315 @@ fn should_repeat() {
 fn should_panic_on_repeat_overflow() {
     StrExt::repeat("0123456789abcdef", usize::MAX);
 }
+
+#[test]
+fn should_match_ignoring_ascii_case() {
+    assert!(StrExt::ends_with_ignore_ascii_case("photo.JPG", ".jpg"));
+    assert!(StrExt::ends_with_ignore_ascii_case("photo.jpg", ".JpG"));
+    assert!(!StrExt::ends_with_ignore_ascii_case("photo.png", ".jpg"));
+    assert!(!StrExt::ends_with_ignore_ascii_case("jpg", ".jpg"));
+
+    assert!(StrExt::starts_with_ignore_ascii_case("Content-Type: text", "content-type"));
+    assert!(StrExt::starts_with_ignore_ascii_case("anything", ""));
+    assert!(!StrExt::starts_with_ignore_ascii_case("Content", "content-type"));
+
+    assert!(StrExt::contains_ignore_ascii_case("Grüße, JÜRGEN", "jÜrgen"));
+    assert!(StrExt::contains_ignore_ascii_case("Grüße", ""));
+    assert!(!StrExt::contains_ignore_ascii_case("gr", "grüße"));
+}
+
+#[test]
+fn should_not_fold_non_ascii_when_ignoring_ascii_case() {
+    assert!(!StrExt::starts_with_ignore_ascii_case("Äpfel", "äpfel"));
+    assert!(!StrExt::ends_with_ignore_ascii_case("JÜRGEN", "jürgen"));
+    assert!(!StrExt::contains_ignore_ascii_case("Grüße, JÜRGEN", "jürgen"));
+    assert!(StrExt::contains_ignore_ascii_case("Grüße, JÜRGEN", "ÜRG"));
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+fn should_elide_middle() {
+    const PATH: &str = "/very/long/path/to/some/file.rs";
+    assert_eq!(StrExt::elide_middle(PATH, 20, "…"), "/very/long…e/file.rs");
+    assert_eq!(StrExt::elide_middle(PATH, 20, "…").chars().count(), 20);
+
+    const HASH: &str = "9f38abe2e6aa643f170f5cfd3e43066914d2c8e6a1b0f3d2c4e5f6a7b8c9d0e1";
+    assert_eq!(HASH.len(), 64);
+    assert_eq!(StrExt::elide_middle(HASH, 11, "..."), "9f38...d0e1");
+
+    assert_eq!(StrExt::elide_middle(PATH, PATH.len(), "…"), PATH);
+    assert_eq!(StrExt::elide_middle(PATH, PATH.len() + 1, "…"), PATH);
+    assert_eq!(StrExt::elide_middle(PATH, 2, "..."), "..");
+    assert_eq!(StrExt::elide_middle(PATH, 3, "..."), "...");
+    assert_eq!(StrExt::elide_middle(PATH, 0, "..."), "");
+}
+
+#[test]
+fn should_elide_middle_multi_byte() {
+    const TEXT: &str = "単語単語単語単語";
+    assert_eq!(StrExt::elide_middle(TEXT, 5, "-"), "単語-単語");
+    assert_eq!(StrExt::elide_middle(TEXT, 4, "-"), "単語-語");
+    assert_eq!(StrExt::elide_middle("1単語8", 4, ""), "1単語8");
+    assert_eq!(StrExt::elide_middle("1単語8", 3, ""), "1単8");
+}
+
+#[test]
+fn should_zfill() {
+    assert_eq!(StrExt::zfill("42", 4), "0042");
+    assert_eq!(StrExt::zfill("-42", 5), "-0042");
+    assert_eq!(StrExt::zfill("+42", 3), "+42");
+    assert_eq!(StrExt::zfill("12345", 3), "12345");
+    assert_eq!(StrExt::zfill("", 2), "00");
+    assert_eq!(StrExt::zfill("-", 2), "-0");
+    assert_eq!(StrExt::zfill("ab", 4), "00ab");
+    assert_eq!(StrExt::zfill("語", 3), "00語");
+
+    let mut stroka = stroka::String::new_str("-7");
+    stroka.zfill_in_place(3);
+    assert_eq!(stroka, "-07");
+    stroka.zfill_in_place(1);
+    assert_eq!(stroka, "-07");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+fn should_zfill_outside_sso_cap() {
+    let mut stroka = stroka::String::new_str("-123456789");
+    assert!(!stroka.is_alloc());
+    stroka.zfill_in_place(20);
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka, "-0000000000123456789");
+    assert_eq!(StrExt::zfill("+1", 16), "+000000000000001");
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "exceeds inline capacity")]
+fn should_panic_on_sso_set_len_above_capacity() {
+    let mut stroka = stroka::String::new();
+    assert!(!stroka.is_alloc());
+    unsafe {
+        stroka.set_len(300);
+    }
+}
+
+#[test]
+fn should_set_len_within_sso_capacity() {
+    let mut stroka = stroka::String::new_str("lolka");
+    unsafe {
+        stroka.set_len(3);
+    }
+    assert_eq!(stroka, "lol");
+}
+
+#[test]
+fn should_strip_affixes_into_owned() {
+    let res = "lolka".strip_prefix_owned("lolka").expect("to strip");
+    assert_eq!(res, "");
+    assert!(!res.is_alloc());
+    let res = "lolka".strip_suffix_owned("lolka").expect("to strip");
+    assert_eq!(res, "");
+    assert!(!res.is_alloc());
+
+    assert_eq!("lolka".strip_prefix_owned("ka"), None);
+    assert_eq!("lolka".strip_suffix_owned("lo"), None);
+    assert_eq!("lolka".strip_prefix_char_owned('k'), None);
+    assert_eq!("".strip_suffix_char_owned('a'), None);
+
+    assert_eq!("lolka".strip_prefix_owned("").expect("to strip"), "lolka");
+    assert_eq!("単語lolka".strip_prefix_owned("単語").expect("to strip"), "lolka");
+    assert_eq!("lolka単語".strip_suffix_owned("語").expect("to strip"), "lolka単");
+    assert_eq!("❤lolka".strip_prefix_char_owned('❤').expect("to strip"), "lolka");
+    assert_eq!("lolka❤".strip_suffix_char_owned('❤').expect("to strip"), "lolka");
+    assert_eq!("単".strip_suffix_char_owned('単').expect("to strip"), "");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+fn should_strip_affixes_into_owned_outside_sso_cap() {
+    let res = "prefix:lolka lol lolid by loli".strip_prefix_owned("prefix:").expect("to strip");
+    assert_eq!(res, "lolka lol lolid by loli");
+    assert!(res.is_alloc());
+
+    let res = "prefix:lolka lol lolid by loli".strip_suffix_owned(" lol lolid by loli").expect("to strip");
+    assert_eq!(res, "prefix:lolka");
+    assert!(!res.is_alloc());
+}
+
+#[test]
+fn should_find_nth_char() {
+    assert_eq!("".find_nth('.', 0), None);
+    assert_eq!("a.b.c".find_nth('.', 0), Some(1));
+    assert_eq!("a.b.c".find_nth('.', 1), Some(3));
+    assert_eq!("a.b.c".find_nth('.', 2), None);
+    assert_eq!("a.b.c".find_nth('.', usize::MAX), None);
+    assert_eq!(".a.".find_nth('.', 0), Some(0));
+    assert_eq!(".a.".find_nth('.', 1), Some(2));
+    assert_eq!("単❤語❤".find_nth('❤', 0), Some(3));
+    assert_eq!("単❤語❤".find_nth('❤', 1), Some(9));
+    assert_eq!("単❤語❤".find_nth('語', 1), None);
+}
+
+#[test]
+fn should_rsplit_once_into_owned() {
+    assert_eq!("".rsplit_once_owned('.'), None);
+    assert_eq!("lolka".rsplit_once_owned('.'), None);
+
+    let (left, right) = "a.b.c".rsplit_once_owned('.').expect("to split");
+    assert_eq!(left, "a.b");
+    assert_eq!(right, "c");
+    assert!(!left.is_alloc());
+
+    let (left, right) = ".lolka".rsplit_once_owned('.').expect("to split");
+    assert_eq!(left, "");
+    assert_eq!(right, "lolka");
+
+    let (left, right) = "lolka.".rsplit_once_owned('.').expect("to split");
+    assert_eq!(left, "lolka");
+    assert_eq!(right, "");
+
+    let (left, right) = "単❤語❤lolka".rsplit_once_owned('❤').expect("to split");
+    assert_eq!(left, "単❤語");
+    assert_eq!(right, "lolka");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+fn should_rsplit_once_into_owned_outside_sso_cap() {
+    let (left, right) = "lolka.lol.lolid.by.loli.prefix:lol".rsplit_once_owned('.').expect("to split");
+    assert_eq!(left, "lolka.lol.lolid.by.loli");
+    assert!(left.is_alloc());
+    assert_eq!(left.capacity(), left.len());
+    assert_eq!(right, "prefix:lol");
+    assert!(!right.is_alloc());
+}
+
+#[test]
+fn should_escape_for_single_line() {
+    let res = "lolka \"❤\"".escape_for_single_line();
+    assert_eq!(res, "lolka \"❤\"");
+    assert!(!res.is_alloc());
+
+    assert_eq!("line\r\nnext".escape_for_single_line(), "line\\r\\nnext");
+    assert_eq!("a\tb\\c".escape_for_single_line(), "a\\tb\\c");
+    assert_eq!("C:\\lolka".escape_for_single_line(), "C:\\lolka");
+    assert_eq!("\u{7}[0m".escape_for_single_line(), "\\u{0007}[0m");
+    assert_eq!("".escape_for_single_line(), "");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+fn should_escape_mixed_content_for_single_line() {
+    assert_eq!("\u{7}\u{1b}[0m\u{7f}".escape_for_single_line(), "\\u{0007}\\u{001b}[0m\\u{007f}");
+    assert_eq!("単\n語\u{85}'".escape_for_single_line(), "単\\n語\\u{0085}'");
+    assert_eq!("GET /\r\nHost: 単語\r\n\r\n".escape_for_single_line(), "GET /\\r\\nHost: 単語\\r\\n\\r\\n");
+}
+
+#[test]
+fn should_count_words() {
+    assert_eq!("".word_count(), 0);
+    assert_eq!("   \t\n".word_count(), 0);
+    assert_eq!("lolka".word_count(), 1);
+    assert_eq!("  lol   lolka\t\tlolid \n".word_count(), 3);
+    assert_eq!("単語\u{3000}lolka\u{a0}❤".word_count(), 3);
+}
+
+#[test]
+fn should_count_char_frequency() {
+    assert_eq!("".char_frequency('l'), 0);
+    assert_eq!("lolka lol".char_frequency('l'), 4);
+    assert_eq!("lolka".char_frequency('L'), 0);
+    assert_eq!("単語単❤単".char_frequency('単'), 3);
+}
+
+#[test]
+fn should_find_longest_word() {
+    assert_eq!("".longest_word(), None);
+    assert_eq!(" \t ".longest_word(), None);
+    assert_eq!("lol  lolka\tlolid".longest_word(), Some("lolka"));
+    assert_eq!("ab cd ef".longest_word(), Some("ab"));
+    //Measured in characters, not bytes
+    assert_eq!("単語 lol".longest_word(), Some("lol"));
+    assert_eq!("lo\u{3000}単語単".longest_word(), Some("単語単"));
+}
+
+#[test]
+fn should_split_at_char() {
+    let stroka = stroka::String::new_str("単語❤lolka");
+    assert_eq!(stroka.split_at_char(0), ("", "単語❤lolka"));
+    assert_eq!(stroka.split_at_char(1), ("単", "語❤lolka"));
+    assert_eq!(stroka.split_at_char(3), ("単語❤", "lolka"));
+    assert_eq!(stroka.split_at_char(8), ("単語❤lolka", ""));
+    assert_eq!(stroka.checked_split_at_char(8), Some(("単語❤lolka", "")));
+    assert_eq!(stroka.checked_split_at_char(9), None);
+    assert_eq!(stroka::String::new().checked_split_at_char(0), Some(("", "")));
+    assert_eq!(stroka::String::new().checked_split_at_char(1), None);
+}
+
+#[test]
+#[should_panic(expected = "char index 6 is out of bounds")]
+fn should_panic_on_split_at_char_out_of_bounds() {
+    stroka::String::new_str("lolka").split_at_char(6);
+}
+
+#[test]
+fn should_split_at_char_owned() {
+    let (head, tail) = stroka::String::new_str("単語lolka").split_at_char_owned(2);
+    assert_eq!(head, "単語");
+    assert_eq!(tail, "lolka");
+    assert!(!head.is_alloc());
+    assert!(!tail.is_alloc());
+
+    let (head, tail) = stroka::String::new_str("lolka").split_at_char_owned(5);
+    assert_eq!((head.as_str(), tail.as_str()), ("lolka", ""));
+    let (head, tail) = stroka::String::new_str("lolka").split_at_char_owned(0);
+    assert_eq!((head.as_str(), tail.as_str()), ("", "lolka"));
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+fn should_split_at_char_owned_outside_sso_cap() {
+    let stroka = stroka::String::new_str("lolka lol lolid by loli ❤ 単語単語単語単語");
+    let ptr = stroka.as_ptr();
+
+    let (head, tail) = stroka.clone().split_at_char_owned(5);
+    assert_eq!(head, "lolka");
+    assert!(!head.is_alloc());
+    assert_eq!(tail, " lol lolid by loli ❤ 単語単語単語単語");
+    assert!(tail.is_alloc());
+
+    let (head, tail) = stroka.split_at_char_owned(25);
+    assert_eq!(head, "lolka lol lolid by loli ❤");
+    assert_eq!(head.as_ptr(), ptr);
+    assert_eq!(tail, " 単語単語単語単語");
+}
+
+#[test]
+fn should_sort_by_case_insensitive_key() {
+    let mut names = ["äpple", "banana", "Apple", "apple", "APPLE", "Banana", "cherry"];
+    names.sort_by(|left, right| left.to_sort_key().cmp(&right.to_sort_key()).then_with(|| left.cmp(right)));
+    assert_eq!(names, ["APPLE", "Apple", "apple", "äpple", "Banana", "banana", "cherry"]);
+
+    assert_eq!("Apple".to_sort_key(), "apple".to_sort_key());
+    assert_eq!("äpple".to_sort_key(), "apple");
+    assert_eq!("Äpple".to_sort_key(), "apple");
+    assert_eq!("a\u{308}pple".to_sort_key(), "apple");
+    assert!("äpple".to_sort_key() < "zebra".to_sort_key());
+    assert_eq!("Œuvre Łódź".to_sort_key(), "oeuvre lodz");
+    assert_eq!("Straße".to_sort_key(), "strasse");
+    assert_eq!("単語 Σ".to_sort_key(), "単語 σ");
+
+    //Stable sort keeps original order of equal keys
+    let mut names = ["b", "B", "a", "A"];
+    names.sort_by_key(|name| name.to_sort_key());
+    assert_eq!(names, ["a", "A", "b", "B"]);
+    assert!(!"Apple".to_sort_key().is_alloc());
+}
+
+#[test]
+fn should_produce_idempotent_sort_key() {
+    for text in ["lolka", "LoLkA", "GRÜSSE", "Straße", "ΣΊΣΥΦΟΣ", "İstanbul", ""] {
+        let key = text.to_sort_key();
+        assert_eq!(key.to_sort_key(), key, "{}", text);
+    }
+}
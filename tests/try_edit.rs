@@ -0,0 +1,233 @@
+use core::ops::Bound;
+use stroka::{BoundaryError, RangeError};
+
+#[test]
+pub fn should_try_remove() {
+    const TEXT: &str = "1単語8";
+    let mut stroka = stroka::String::new_str(TEXT);
+
+    assert_eq!(stroka.try_remove(2), Err(BoundaryError::NotCharBoundary { index: 2 }));
+    assert_eq!(stroka.try_remove(TEXT.len()), Err(BoundaryError::OutOfBounds { index: TEXT.len(), len: TEXT.len() }));
+    assert_eq!(stroka.try_remove(usize::MAX), Err(BoundaryError::OutOfBounds { index: usize::MAX, len: TEXT.len() }));
+    assert_eq!(stroka, TEXT);
+
+    assert_eq!(stroka.try_remove(1), Ok('単'));
+    assert_eq!(stroka, "1語8");
+}
+
+#[test]
+pub fn should_try_insert_str() {
+    const TEXT: &str = "1単語8";
+    let mut stroka = stroka::String::new_str(TEXT);
+
+    assert_eq!(stroka.try_insert_str(2, "-"), Err(BoundaryError::NotCharBoundary { index: 2 }));
+    assert_eq!(stroka.try_insert_str(usize::MAX, "-"), Err(BoundaryError::OutOfBounds { index: usize::MAX, len: TEXT.len() }));
+    assert_eq!(stroka, TEXT);
+
+    assert_eq!(stroka.try_insert_str(TEXT.len(), "-"), Ok(()));
+    assert_eq!(stroka, "1単語8-");
+    assert_eq!(stroka.try_insert_str(1, "-"), Ok(()));
+    assert_eq!(stroka, "1-単語8-");
+}
+
+#[test]
+pub fn should_try_replace_range() {
+    const TEXT: &str = "1単語8";
+    let mut stroka = stroka::String::new_str(TEXT);
+
+    assert_eq!(stroka.try_replace_range(2.., "-"), Err(RangeError::Start(BoundaryError::NotCharBoundary { index: 2 })));
+    assert_eq!(stroka.try_replace_range(..5, "-"), Err(RangeError::End(BoundaryError::NotCharBoundary { index: 5 })));
+    assert_eq!(stroka.try_replace_range(..=TEXT.len(), "-"), Err(RangeError::End(BoundaryError::OutOfBounds { index: TEXT.len() + 1, len: TEXT.len() })));
+    assert_eq!(stroka.try_replace_range((Bound::Included(4), Bound::Excluded(1)), "-"), Err(RangeError::StartAfterEnd { start: 4, end: 1 }));
+    assert_eq!(stroka, TEXT);
+
+    assert_eq!(stroka.try_replace_range(1..4, "-"), Ok(()));
+    assert_eq!(stroka, "1-語8");
+}
+
+#[test]
+pub fn should_try_push_within_inline_capacity() {
+    const CAP: usize = stroka::String::INLINE_CAPACITY;
+    let mut stroka = stroka::String::new_str("lolka");
+    let ptr = stroka.as_ptr();
+
+    stroka.try_push('❤').expect("to fit");
+    stroka.try_push_str(&"l".repeat(CAP - 8)).expect("to fit");
+    assert_eq!(stroka.len(), CAP);
+    assert!(!stroka.is_alloc());
+    assert_eq!(stroka.as_ptr(), ptr);
+
+    let error = stroka.try_push('❤').expect_err("to overflow");
+    assert_eq!(error.missing(), 3);
+    assert_eq!(error.to_string(), "insufficient capacity, missing 3 bytes");
+    assert_eq!(stroka.try_push_str("lolka").expect_err("to overflow").missing(), 5);
+    stroka.try_push_str("").expect("to fit empty");
+
+    assert_eq!(stroka.len(), CAP);
+    assert!(!stroka.is_alloc());
+    assert_eq!(stroka.as_ptr(), ptr);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_try_push_within_heap_capacity() {
+    let mut stroka = stroka::String::with_capacity(32);
+    let ptr = stroka.as_ptr();
+    stroka.try_push_str("lolka lol lolid by loli").expect("to fit");
+    stroka.try_push('単').expect("to fit");
+    assert_eq!(stroka.len(), 26);
+
+    assert_eq!(stroka.try_push_str("lolka lol").expect_err("to overflow").missing(), 3);
+    stroka.try_push_str("lolka ").expect("to fit");
+    assert_eq!(stroka.try_push('l').expect_err("to overflow").missing(), 1);
+
+    assert_eq!(stroka, "lolka lol lolid by loli単lolka ");
+    assert_eq!(stroka.capacity(), 32);
+    assert_eq!(stroka.as_ptr(), ptr);
+}
+
+#[test]
+pub fn should_push_str_within_inline_capacity() {
+    const CAP: usize = stroka::String::INLINE_CAPACITY;
+    let mut stroka = stroka::String::new_str(&"l".repeat(CAP - 2));
+    assert_eq!(stroka.push_str_within_capacity("aä"), "ä");
+    assert_eq!(stroka.len(), CAP - 1);
+    assert_eq!(stroka.push_str_within_capacity("ä"), "ä");
+    assert_eq!(stroka.push_str_within_capacity("lä"), "ä");
+    assert_eq!(stroka.len(), CAP);
+
+    //Zero remaining capacity
+    assert_eq!(stroka.push_str_within_capacity("lolka"), "lolka");
+    assert_eq!(stroka.push_str_within_capacity(""), "");
+    assert!(!stroka.is_alloc());
+
+    //Exact fit
+    let mut stroka = stroka::String::new_str(&"l".repeat(CAP - 3));
+    assert_eq!(stroka.push_str_within_capacity("單"), "");
+    assert_eq!(stroka.len(), CAP);
+    assert!(!stroka.is_alloc());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_push_str_within_heap_capacity() {
+    let mut stroka = stroka::String::with_capacity(32);
+    let ptr = stroka.as_ptr();
+    assert_eq!(stroka.push_str_within_capacity("lolka lol lolid by loli"), "");
+    assert_eq!(stroka.push_str_within_capacity("lo単語❤"), "❤");
+    assert_eq!(stroka.len(), 31);
+    assert_eq!(stroka.push_str_within_capacity("❤"), "❤");
+    assert_eq!(stroka.push_str_within_capacity("l❤"), "❤");
+    assert_eq!(stroka.push_str_within_capacity("lol"), "lol");
+
+    assert_eq!(stroka, "lolka lol lolid by lolilo単語l");
+    assert_eq!(stroka.capacity(), 32);
+    assert_eq!(stroka.as_ptr(), ptr);
+}
+
+fn check_ranges_against_std(text: &str) {
+    let stroka = stroka::String::new_str(text);
+    let len = text.len();
+    let mut bounds = vec![Bound::Unbounded, Bound::Included(usize::MAX), Bound::Excluded(usize::MAX)];
+    for idx in 0..=len + 1 {
+        bounds.push(Bound::Included(idx));
+        bounds.push(Bound::Excluded(idx));
+    }
+
+    for start in bounds.iter().cloned() {
+        for end in bounds.iter().cloned() {
+            let res = stroka.validate_range((start, end));
+            match text.get((start, end)) {
+                Some(expected) => {
+                    let range = res.expect("to be valid");
+                    assert_eq!(&text[range], expected, "{:?}..{:?}", start, end);
+                },
+                None => {
+                    res.expect_err("to be invalid");
+                },
+            }
+        }
+    }
+
+    for idx in 0..=len + 1 {
+        assert_eq!(stroka.validate_range(idx..).ok(), text.get(idx..).map(|_| idx..len));
+        assert_eq!(stroka.validate_range(..idx).ok(), text.get(..idx).map(|_| 0..idx));
+        assert_eq!(stroka.validate_range(..=idx).ok(), text.get(..=idx).map(|_| 0..idx + 1));
+        assert_eq!(stroka.validate_range(idx..idx).ok(), text.get(idx..idx).map(|_| idx..idx));
+        assert_eq!(stroka.validate_range(0..=idx).ok(), text.get(0..=idx).map(|_| 0..idx + 1));
+    }
+    assert_eq!(stroka.validate_range(..), Ok(0..len));
+}
+
+#[test]
+pub fn should_validate_ranges_as_std() {
+    check_ranges_against_std("");
+    check_ranges_against_std("lolka");
+    check_ranges_against_std("単語❤");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_validate_heap_ranges_as_std() {
+    check_ranges_against_std("lolka 単語 lolid by ❤");
+}
+
+#[test]
+pub fn should_report_validate_range_errors() {
+    let stroka = stroka::String::new_str("単語");
+    assert_eq!(stroka.validate_range(1..3), Err(RangeError::Start(BoundaryError::NotCharBoundary { index: 1 })));
+    assert_eq!(stroka.validate_range(0..7), Err(RangeError::End(BoundaryError::OutOfBounds { index: 7, len: 6 })));
+    assert_eq!(stroka.validate_range(..=3), Err(RangeError::End(BoundaryError::NotCharBoundary { index: 4 })));
+    assert_eq!(stroka.validate_range((Bound::Included(6), Bound::Excluded(3))), Err(RangeError::StartAfterEnd { start: 6, end: 3 }));
+}
+
+#[test]
+#[should_panic(expected = "byte index 1 is not a char boundary")]
+pub fn should_panic_on_invalid_drain_range_with_same_diagnostic() {
+    let mut stroka = stroka::String::new_str("単語");
+    stroka.drain(1..3);
+}
+
+#[test]
+#[should_panic(expected = "start '6' is greater than end '3'")]
+pub fn should_panic_on_reversed_remove_range_with_same_diagnostic() {
+    let mut stroka = stroka::String::new_str("単語");
+    #[allow(clippy::reversed_empty_ranges)]
+    stroka.remove_range(6..3);
+}
+
+#[test]
+#[should_panic(expected = "byte index 1 is not a char boundary or out of bounds of string with length 6")]
+pub fn should_panic_on_non_char_boundary_remove_with_same_diagnostic() {
+    let mut stroka = stroka::String::new_str("単語");
+    stroka.remove(1);
+}
+
+#[test]
+#[should_panic(expected = "byte index 7 is not a char boundary or out of bounds of string with length 6")]
+pub fn should_panic_on_out_of_bounds_remove_with_same_diagnostic() {
+    let mut stroka = stroka::String::new_str("単語");
+    stroka.remove(7);
+}
+
+#[test]
+#[should_panic(expected = "byte index 6 is not a char boundary or out of bounds of string with length 6")]
+pub fn should_panic_on_remove_at_end_with_same_diagnostic() {
+    let mut stroka = stroka::String::new_str("単語");
+    stroka.remove(6);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+#[should_panic(expected = "byte index 10 is not a char boundary or out of bounds of string with length 24")]
+pub fn should_panic_on_non_char_boundary_heap_remove_with_same_diagnostic() {
+    let mut stroka = stroka::String::new_str("123456789単語123456789");
+    stroka.remove(10);
+}
+
+#[test]
+#[should_panic(expected = "byte index 1 is not a char boundary or out of bounds of string with length 6")]
+pub fn should_panic_on_non_char_boundary_insert_with_same_diagnostic() {
+    let mut stroka = stroka::String::new_str("単語");
+    stroka.insert(1, 'l');
+}
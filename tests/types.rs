@@ -0,0 +1,36 @@
+use core::convert::TryFrom;
+
+use stroka::types::NonEmptyString;
+
+#[test]
+pub fn should_reject_empty_non_empty_string() {
+    assert_eq!(NonEmptyString::new(stroka::String::new()), Err(stroka::EmptyError));
+    assert_eq!(NonEmptyString::try_from(""), Err(stroka::EmptyError));
+    assert_eq!("".parse::<NonEmptyString>(), Err(stroka::EmptyError));
+    assert_eq!(stroka::EmptyError.to_string(), "string must not be empty");
+}
+
+#[test]
+pub fn should_construct_non_empty_string() {
+    let string = NonEmptyString::new(stroka::String::new_str("lolka")).expect("to be non-empty");
+    assert_eq!(string, "lolka");
+    assert_eq!(string.len(), 5);
+    assert_eq!(format!("{} {:?}", string, string), "lolka \"lolka\"");
+
+    let string = NonEmptyString::try_from("単語").expect("to be non-empty");
+    assert_eq!(string.as_str(), "単語");
+    let string: NonEmptyString = " ".parse().expect("to be non-empty");
+    assert_eq!(stroka::String::from(string), " ");
+}
+
+#[test]
+pub fn should_keep_non_empty_string_invariant_on_mutation() {
+    let mut string = NonEmptyString::try_from("l").expect("to be non-empty");
+    string.push_str("");
+    assert_eq!(string, "l");
+    string.push('o');
+    string.push_str("lka");
+    assert_eq!(string, "lolka");
+    assert!(!string.is_empty());
+    assert_eq!(string.into_inner(), "lolka");
+}
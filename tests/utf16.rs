@@ -10,6 +10,7 @@ pub fn should_convert_from_utf16() {
 }
 
 #[test]
+#[cfg(not(feature = "sso-only"))]
 pub fn should_fail_from_invalid_utf16() {
     let buf = [0xD834u16, 0xDD1E, 0x006d, 0x0075, 0xD800, 0x0069, 0x0063];
     stroka::String::from_utf16(&buf).expect_err("Should fail to parse invalid utf-16");
@@ -18,3 +19,72 @@ pub fn should_fail_from_invalid_utf16() {
     let res = stroka::String::from_utf16_lossy(&buf);
     assert_eq!(res, "𝄞mus\u{FFFD}ic\u{FFFD}");
 }
+
+fn to_le(text: &str) -> Vec<u8> {
+    text.encode_utf16().flat_map(u16::to_le_bytes).collect()
+}
+
+fn to_be(text: &str) -> Vec<u8> {
+    text.encode_utf16().flat_map(u16::to_be_bytes).collect()
+}
+
+#[test]
+pub fn should_convert_from_utf16_bytes() {
+    const TEXT: &str = "ろり text";
+    assert_eq!(stroka::String::from_utf16le(&to_le(TEXT)).expect("To parse utf-16le"), TEXT);
+    assert_eq!(stroka::String::from_utf16be(&to_be(TEXT)).expect("To parse utf-16be"), TEXT);
+    assert_eq!(stroka::String::from_utf16le_lossy(&to_le(TEXT)), TEXT);
+    assert_eq!(stroka::String::from_utf16be_lossy(&to_be(TEXT)), TEXT);
+
+    assert_eq!(stroka::String::from_utf16le(b"a\x00"), Ok(stroka::String::new_str("a")));
+    assert_eq!(stroka::String::from_utf16be(b"a\x00"), Ok(stroka::String::new_str("愀")));
+    assert_eq!(stroka::String::from_utf16le(b""), Ok(stroka::String::new()));
+}
+
+#[test]
+pub fn should_fail_from_invalid_utf16_bytes() {
+    assert_eq!(stroka::String::from_utf16le(b"a\x00b"), Err(stroka::FromUtf16Error::OddLength));
+    assert_eq!(stroka::String::from_utf16be(b"\x00"), Err(stroka::FromUtf16Error::OddLength));
+    assert_eq!(stroka::String::from_utf16le_lossy(b"a\x00b"), "a\u{FFFD}");
+    assert_eq!(stroka::String::from_utf16be_lossy(b"\x00a\x00"), "a\u{FFFD}");
+
+    let error = stroka::String::from_utf16le(b"a\x00\x00\xD8b\x00").expect_err("unpaired surrogate");
+    match error {
+        stroka::FromUtf16Error::Decode(error) => assert_eq!(error.unpaired_surrogate(), 0xD800),
+        stroka::FromUtf16Error::OddLength => panic!("unexpected error"),
+    }
+    assert_eq!(stroka::String::from_utf16le_lossy(b"a\x00\x00\xD8b\x00"), "a\u{FFFD}b");
+    assert_eq!(stroka::String::from_utf16be_lossy(b"\xDC\x00\x00a"), "\u{FFFD}a");
+}
+
+#[test]
+pub fn should_push_utf16() {
+    let mut stroka = stroka::String::new_str("ろ");
+    stroka.push_utf16(&"り".encode_utf16().collect::<Vec<_>>()).expect("To parse utf-16");
+    stroka.push_utf16_lossy(&[0x0074, 0xD800]);
+    assert_eq!(stroka, "ろりt\u{FFFD}");
+
+    stroka.push_utf16(&[0x0065, 0xDD1E, 0x0078]).expect_err("Should fail on unpaired surrogate");
+    assert_eq!(stroka, "ろりt\u{FFFD}");
+    assert!(!stroka.is_alloc());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_push_utf16_surrogates_across_sso_boundary() {
+    const TEXT: &str = "lolka lol𝄞𝄞";
+    let mut stroka = stroka::String::new_str("lolka ");
+    stroka.push_utf16(&"lol𝄞".encode_utf16().collect::<Vec<_>>()).expect("To parse utf-16");
+    assert!(!stroka.is_alloc());
+    stroka.push_utf16(&"𝄞".encode_utf16().collect::<Vec<_>>()).expect("To parse utf-16");
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka, TEXT);
+
+    let mut stroka = stroka::String::new_str("lolka lol𝄞");
+    stroka.push_utf16(&[0xD834, 0xDD1E, 0xD834]).expect_err("Should fail on unpaired surrogate");
+    assert_eq!(stroka, "lolka lol𝄞");
+
+    let mut stroka = stroka::String::new_str("lolka lol𝄞");
+    stroka.push_utf16_lossy(&[0xD834, 0xDD1E, 0xD834]);
+    assert_eq!(stroka, "lolka lol𝄞𝄞\u{FFFD}");
+}
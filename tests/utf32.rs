@@ -0,0 +1,35 @@
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_from_utf32() {
+    const TEXT: &str = "ろり text";
+    let buf = TEXT.chars().map(|ch| ch as u32).collect::<Vec<_>>();
+    let res = stroka::String::from_utf32(&buf).expect("To parse utf-32");
+    assert_eq!(TEXT, res);
+    assert!(!res.is_alloc());
+
+    let res = stroka::String::from_utf32_lossy(&buf);
+    assert_eq!(TEXT, res);
+    assert!(!res.is_alloc());
+
+    let buf = [0x1D11Eu32, 0x6d, 0x75, 0x73, 0x69, 0x63, 0x1D11E, 0x1D11E];
+    let res = stroka::String::from_utf32(&buf).expect("To parse utf-32");
+    assert_eq!(res, "𝄞music𝄞𝄞");
+    assert!(res.is_alloc());
+}
+
+#[test]
+pub fn should_fail_from_invalid_utf32() {
+    let buf = [0x6du32, 0x75, 0xD800, 0x69];
+    let error = stroka::String::from_utf32(&buf).expect_err("Should fail on surrogate");
+    assert_eq!(error.index(), 2);
+    assert_eq!(error.value(), 0xD800);
+
+    let buf = [0x6du32, 0x110000];
+    let error = stroka::String::from_utf32(&buf).expect_err("Should fail on out of range value");
+    assert_eq!(error.index(), 1);
+    assert_eq!(error.value(), 0x110000);
+
+    let buf = [0x6du32, 0xDFFF, 0x75, 0x110000];
+    let res = stroka::String::from_utf32_lossy(&buf);
+    assert_eq!(res, "m\u{FFFD}u\u{FFFD}");
+}
@@ -0,0 +1,181 @@
+use core::convert::TryFrom;
+
+#[test]
+pub fn should_convert_from_utf8() {
+    let stroka = stroka::String::from_utf8(b"lolka".to_vec()).expect("valid ASCII");
+    assert_eq!(stroka, "lolka");
+    assert!(!stroka.is_alloc());
+
+    let mut bytes = Vec::with_capacity(64);
+    bytes.extend_from_slice("単語".as_bytes());
+    let stroka = stroka::String::from_utf8(bytes).expect("valid UTF-8");
+    assert_eq!(stroka, "単語");
+    assert!(!stroka.is_alloc());
+
+    let stroka = stroka::String::from_utf8(Vec::new()).expect("empty");
+    assert_eq!(stroka, "");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_from_utf8_outside_sso_cap() {
+    const TEXT: &str = "lolka lol lolid by loli 単語";
+    let stroka = stroka::String::from_utf8(TEXT.as_bytes().to_vec()).expect("valid UTF-8");
+    assert_eq!(stroka, TEXT);
+    assert!(stroka.is_alloc());
+}
+
+#[test]
+pub fn should_fail_from_invalid_utf8() {
+    let bytes = vec![b'1', 0xe5, 0x8d, 0x98, 0xe8, 0xaa, b'8'];
+    let error = stroka::String::from_utf8(bytes.clone()).expect_err("invalid UTF-8");
+    assert_eq!(error.utf8_error().valid_up_to(), 4);
+    assert_eq!(error.utf8_error(), core::str::from_utf8(&bytes).unwrap_err());
+    assert_eq!(error.as_bytes(), &bytes[..]);
+    assert_eq!(error.into_bytes(), bytes);
+
+    let error = stroka::String::from_utf8(vec![0xff]).expect_err("invalid UTF-8");
+    assert_eq!(error.utf8_error().valid_up_to(), 0);
+    assert_eq!(error.utf8_error().error_len(), Some(1));
+}
+
+#[test]
+pub fn should_try_convert_from_bytes() {
+    let stroka = stroka::String::try_from(&b""[..]).expect("empty");
+    assert_eq!(stroka, "");
+
+    let text = "1単語8 lolka";
+    assert_eq!(text.len(), 14);
+    let stroka = stroka::String::try_from(text.as_bytes()).expect("valid UTF-8");
+    assert_eq!(stroka, text);
+    assert!(!stroka.is_alloc());
+
+    let stroka = stroka::String::try_from(text.as_bytes().to_vec()).expect("valid UTF-8");
+    assert_eq!(stroka, text);
+    assert!(!stroka.is_alloc());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_try_convert_from_bytes_outside_sso_cap() {
+    let text = "1単語8 lolka!";
+    assert_eq!(text.len(), 15);
+    let stroka = stroka::String::try_from(text.as_bytes()).expect("valid UTF-8");
+    assert_eq!(stroka, text);
+    assert!(stroka.is_alloc());
+
+    let stroka = stroka::String::try_from(text.as_bytes().to_vec()).expect("valid UTF-8");
+    assert_eq!(stroka, text);
+    assert!(stroka.is_alloc());
+}
+
+#[test]
+pub fn should_fail_try_convert_from_invalid_bytes() {
+    let bytes = [b'1', 0xe5, 0x8d, 0x98, 0xe8, 0xaa, b'8'];
+    let error = stroka::String::try_from(&bytes[..]).expect_err("invalid UTF-8");
+    assert_eq!(error.valid_up_to(), 4);
+    assert_eq!(error.error_len(), Some(2));
+
+    let error = stroka::String::try_from(bytes.to_vec()).expect_err("invalid UTF-8");
+    assert_eq!(error.utf8_error().valid_up_to(), 4);
+    assert_eq!(error.into_bytes(), bytes);
+
+    let error = stroka::String::try_from(&[0xff][..]).expect_err("invalid UTF-8");
+    assert_eq!(error.valid_up_to(), 0);
+    assert_eq!(error.error_len(), Some(1));
+}
+
+#[test]
+pub fn should_convert_from_utf8_unchecked() {
+    let text = "1単語8 lolka";
+    assert_eq!(text.len(), 14);
+    let stroka = unsafe {
+        stroka::String::from_utf8_unchecked(text.as_bytes())
+    };
+    assert_eq!(stroka, text);
+    assert_eq!(stroka.len(), text.len());
+    assert!(!stroka.is_alloc());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_from_utf8_unchecked_outside_sso_cap() {
+    let text = "1単語8 lolka!";
+    assert_eq!(text.len(), 15);
+    let stroka = unsafe {
+        stroka::String::from_utf8_unchecked(text.as_bytes())
+    };
+    assert_eq!(stroka, text);
+    assert_eq!(stroka.len(), text.len());
+    assert!(stroka.is_alloc());
+    assert_eq!(stroka.as_bytes(), text.as_bytes());
+}
+
+#[test]
+pub fn should_convert_into_bytes() {
+    let bytes = stroka::String::new().into_bytes();
+    assert!(bytes.is_empty());
+
+    let bytes = stroka::String::new_str("単語").into_bytes();
+    assert_eq!(bytes, "単語".as_bytes());
+    assert_eq!(bytes.capacity(), bytes.len());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_heap_into_bytes() {
+    const TEXT: &str = "lolka lol lolid by loli 単語";
+    let mut stroka = stroka::String::with_capacity(64);
+    stroka.push_str(TEXT);
+    assert!(stroka.is_alloc());
+
+    let bytes = stroka.into_bytes();
+    assert_eq!(bytes, TEXT.as_bytes());
+    assert_eq!(bytes.capacity(), TEXT.len());
+    assert_eq!(stroka::String::from_utf8(bytes).expect("valid UTF-8"), TEXT);
+}
+
+#[test]
+pub fn should_convert_into_boxed_str() {
+    let boxed = stroka::String::new_str("単語").into_boxed_str();
+    assert_eq!(&*boxed, "単語");
+    assert_eq!(boxed.len(), "単語".len());
+
+    let boxed: Box<str> = stroka::String::new().into();
+    assert_eq!(boxed.len(), 0);
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_convert_heap_into_boxed_str() {
+    const TEXT: &str = "lolka lol lolid by loli 単語";
+    let mut stroka = stroka::String::with_capacity(64);
+    stroka.push_str(TEXT);
+    assert!(stroka.is_alloc());
+
+    let boxed: Box<str> = stroka.into();
+    assert_eq!(&*boxed, TEXT);
+    assert_eq!(boxed.len(), TEXT.len());
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_leak_string() {
+    let leaked = {
+        let stroka = stroka::String::new_str("lolka単語");
+        assert!(!stroka.is_alloc());
+        stroka.leak()
+    };
+    leaked.make_ascii_uppercase();
+    assert_eq!(leaked, "LOLKA単語");
+
+    const TEXT: &str = "lolka lol lolid by loli";
+    let leaked: &'static str = {
+        let stroka = stroka::String::new_str(TEXT);
+        assert!(stroka.is_alloc());
+        stroka.leak()
+    };
+    assert_eq!(leaked, TEXT);
+
+    assert_eq!(stroka::String::new().leak(), "");
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "std")]
+
+fn wide(text: &str) -> Vec<u16> {
+    text.encode_utf16().collect()
+}
+
+#[test]
+pub fn should_decode_wide_string() {
+    let stroka = stroka::String::from_wide(&wide("lolka")).expect("to decode");
+    assert_eq!(stroka, "lolka");
+    assert!(!stroka.is_alloc());
+
+    let stroka = stroka::String::from_wide(&wide("単語😀")).expect("to decode");
+    assert_eq!(stroka, "単語😀");
+
+    stroka::String::from_wide(&[0x6C, 0xD800, 0x6C]).expect_err("to reject lone surrogate");
+    assert_eq!(stroka::String::from_wide_lossy(&[0x6C, 0xD800, 0x6C]), "l\u{FFFD}l");
+    assert_eq!(stroka::String::from_wide_lossy(&wide("単語")), "単語");
+}
+
+#[test]
+pub fn should_decode_wide_string_until_nul() {
+    let mut units = wide("lolka");
+    units.push(0);
+    units.extend(wide("garbage"));
+    assert_eq!(stroka::String::from_wide_nul(&units).expect("to decode"), "lolka");
+
+    assert_eq!(stroka::String::from_wide_nul(&wide("単語")).expect("to decode"), "単語");
+    assert_eq!(stroka::String::from_wide_nul(&[0, 0x6C]).expect("to decode"), "");
+    assert_eq!(stroka::String::from_wide_nul(&[]).expect("to decode"), "");
+
+    //Invalid units after terminator are never looked at.
+    assert_eq!(stroka::String::from_wide_nul(&[0x6C, 0, 0xD800]).expect("to decode"), "l");
+    stroka::String::from_wide_nul(&[0xD800, 0]).expect_err("to reject lone surrogate");
+}
+
+#[test]
+#[cfg(not(feature = "sso-only"))]
+pub fn should_decode_wide_string_outside_sso_cap() {
+    let mut units = wide("C:\\Windows\\System32\\drivers");
+    units.push(0);
+    let stroka = stroka::String::from_wide_nul(&units).expect("to decode");
+    assert_eq!(stroka, "C:\\Windows\\System32\\drivers");
+    assert!(stroka.is_alloc());
+}